@@ -0,0 +1,457 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Instant;
+
+use tokio::runtime::Handle;
+use tokio::sync::{mpsc, Mutex, RwLock};
+
+use segment::entry::entry_point::SegmentEntry;
+use segment::segment::Segment;
+use segment::types::{
+    PayloadInterface, PayloadInterfaceStrict, PayloadKeyType, PointIdType, ScoredPoint,
+    WithPayload, WithPayloadInterface,
+};
+
+use crate::collection_manager::collection_managers::CollectionSearcher;
+use crate::collection_manager::holders::segment_holder::SegmentHolder;
+use crate::metrics::Metrics;
+use crate::operations::payload_ops::PayloadOps;
+use crate::operations::point_ops::PointOperations;
+use crate::operations::types::{
+    CollectionError, CollectionResult, RecommendRequest, ScrollRequest, ScrollResult,
+    SearchRequest, TaskStatus, UpdateResult, UpdateStatus,
+};
+use crate::operations::CollectionUpdateOperations;
+use crate::update_tracker::UpdateTracker;
+use crate::wal::Wal;
+
+/// A non-blocking update, queued for [`Collection::run_async_updates`] once its
+/// WAL record has been written. Carries the same point-delta counts `update`
+/// already computed, so the worker doesn't need to recompute them.
+struct AsyncUpdate {
+    update_id: u64,
+    operation: CollectionUpdateOperations,
+    upserted: u64,
+    deleted: u64,
+}
+
+/// A single collection: its segments, its WAL, and the update task registry
+/// that lets asynchronous writes be polled for completion.
+pub struct Collection {
+    name: String,
+    #[allow(dead_code)]
+    path: PathBuf,
+    segments: Arc<RwLock<SegmentHolder>>,
+    wal: Mutex<Wal>,
+    update_tracker: Arc<UpdateTracker>,
+    metrics: Arc<Metrics>,
+    /// Non-blocking updates are handed to the single consumer task spawned by
+    /// `Collection::new` instead of each getting its own `tokio::spawn`, so
+    /// they are applied to `segments` in the same order their WAL records were
+    /// written in -- a per-call spawn would let concurrent non-blocking
+    /// updates race for the segment lock and apply out of order.
+    async_update_tx: mpsc::UnboundedSender<AsyncUpdate>,
+}
+
+impl Collection {
+    pub fn new(
+        name: String,
+        path: PathBuf,
+        segments: Arc<RwLock<SegmentHolder>>,
+        wal: Wal,
+        update_tracker: UpdateTracker,
+        metrics: Arc<Metrics>,
+    ) -> Self {
+        let update_tracker = Arc::new(update_tracker);
+        let (async_update_tx, async_update_rx) = mpsc::unbounded_channel();
+
+        tokio::spawn(Self::run_async_updates(
+            segments.clone(),
+            update_tracker.clone(),
+            metrics.clone(),
+            name.clone(),
+            async_update_rx,
+        ));
+
+        Collection {
+            name,
+            path,
+            segments,
+            wal: Mutex::new(wal),
+            update_tracker,
+            metrics,
+            async_update_tx,
+        }
+    }
+
+    /// Drains `updates` one at a time, applying each to `segments` in the
+    /// order it was enqueued in. Being the only consumer is what ties live
+    /// apply order back to WAL write order: `update` enqueues under the same
+    /// WAL lock it writes the record under, so enqueue order matches write order.
+    async fn run_async_updates(
+        segments: Arc<RwLock<SegmentHolder>>,
+        update_tracker: Arc<UpdateTracker>,
+        metrics: Arc<Metrics>,
+        name: String,
+        mut updates: mpsc::UnboundedReceiver<AsyncUpdate>,
+    ) {
+        while let Some(update) = updates.recv().await {
+            update_tracker.set(update.update_id, TaskStatus::Processing);
+            let result = {
+                let mut holder = segments.write().await;
+                let result = Self::apply_operation(&mut holder, update.update_id, update.operation);
+                if result.is_ok() {
+                    Self::record_point_deltas_for(&metrics, &name, update.upserted, update.deleted);
+                    Self::refresh_segment_gauges_for(&metrics, &name, &holder);
+                }
+                result
+            };
+            match result {
+                Ok(_) => update_tracker.set(update.update_id, TaskStatus::Completed),
+                Err(err) => update_tracker.set(
+                    update.update_id,
+                    TaskStatus::Failed {
+                        error: err.to_string(),
+                    },
+                ),
+            }
+        }
+    }
+
+    pub fn segments(&self) -> &Arc<RwLock<SegmentHolder>> {
+        &self.segments
+    }
+
+    /// Submits `operation` for execution. When `wait` is true, blocks until it has
+    /// actually been applied to the segments and returns `Completed`. When `wait`
+    /// is false, the operation is durably appended to the WAL and applied by a
+    /// background task; the call returns immediately with `Acknowledged` and an
+    /// `update_id` that [`Collection::update_status`] can poll for completion.
+    pub async fn update(
+        &self,
+        operation: CollectionUpdateOperations,
+        wait: bool,
+    ) -> CollectionResult<UpdateResult> {
+        let (upserted, deleted) = Self::point_deltas(&operation);
+
+        if wait {
+            let update_id = {
+                let mut wal = self.wal.lock().await;
+                wal.write(&operation)
+                    .map_err(|error| CollectionError::ServiceError {
+                        error: error.to_string(),
+                    })?
+            };
+            self.update_tracker.acknowledge(update_id);
+            self.update_tracker.set(update_id, TaskStatus::Processing);
+            let result = {
+                let mut holder = self.segments.write().await;
+                let result = Self::apply_operation(&mut holder, update_id, operation);
+                if result.is_ok() {
+                    self.record_point_deltas(upserted, deleted);
+                    self.refresh_segment_gauges(&holder);
+                }
+                result
+            };
+            match &result {
+                Ok(_) => self.update_tracker.set(update_id, TaskStatus::Completed),
+                Err(err) => self.update_tracker.set(
+                    update_id,
+                    TaskStatus::Failed {
+                        error: err.to_string(),
+                    },
+                ),
+            }
+            result?;
+            Ok(UpdateResult {
+                update_id,
+                status: UpdateStatus::Completed,
+            })
+        } else {
+            // The WAL write and the enqueue onto `async_update_tx` happen under the
+            // same lock with no `.await` between them, so whichever caller's WAL
+            // write is ordered first also enqueues first -- the single consumer in
+            // `run_async_updates` then applies everything in that exact order.
+            let update_id = {
+                let mut wal = self.wal.lock().await;
+                let update_id =
+                    wal.write(&operation)
+                        .map_err(|error| CollectionError::ServiceError {
+                            error: error.to_string(),
+                        })?;
+                // Must happen before `send`: once sent, `run_async_updates` can pick
+                // the update up and advance it past `Acknowledged` on its own task
+                // before this one runs again, and `acknowledge` unconditionally
+                // overwrites whatever status is currently stored.
+                self.update_tracker.acknowledge(update_id);
+                self.async_update_tx
+                    .send(AsyncUpdate {
+                        update_id,
+                        operation,
+                        upserted,
+                        deleted,
+                    })
+                    .expect("async update worker task outlives every Collection handle");
+                update_id
+            };
+            Ok(UpdateResult {
+                update_id,
+                status: UpdateStatus::Acknowledged,
+            })
+        }
+    }
+
+    /// Points a successful operation is about to add/remove, counted ahead of
+    /// applying it. `DeletePointsByFilter` is not counted here: how many points
+    /// it actually removes is only known after matching against live segments.
+    fn point_deltas(operation: &CollectionUpdateOperations) -> (u64, u64) {
+        match operation {
+            CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(points)) => {
+                (points.len() as u64, 0)
+            }
+            CollectionUpdateOperations::PointOperation(PointOperations::DeletePoints { ids }) => {
+                (0, ids.len() as u64)
+            }
+            _ => (0, 0),
+        }
+    }
+
+    fn record_point_deltas(&self, upserted: u64, deleted: u64) {
+        Self::record_point_deltas_for(&self.metrics, &self.name, upserted, deleted);
+    }
+
+    fn record_point_deltas_for(metrics: &Metrics, name: &str, upserted: u64, deleted: u64) {
+        if upserted > 0 {
+            metrics.record_upserted(name, upserted);
+        }
+        if deleted > 0 {
+            metrics.record_deleted(name, deleted);
+        }
+    }
+
+    fn refresh_segment_gauges(&self, holder: &SegmentHolder) {
+        Self::refresh_segment_gauges_for(&self.metrics, &self.name, holder);
+    }
+
+    fn refresh_segment_gauges_for(metrics: &Metrics, name: &str, holder: &SegmentHolder) {
+        let mut indexed_points = 0i64;
+        for (_, segment) in holder.iter() {
+            let segment = segment.try_read().expect("segment lock contended");
+            indexed_points += segment.points_count() as i64;
+        }
+        metrics.set_segment_count(name, holder.len() as i64);
+        metrics.set_indexed_points(name, indexed_points);
+    }
+
+    /// Polls the status of a previously submitted update.
+    pub fn update_status(&self, update_id: u64) -> Option<TaskStatus> {
+        self.update_tracker.get(update_id)
+    }
+
+    /// Applies a single WAL-recorded operation to `holder`. Used both for live
+    /// updates and for WAL replay in `load_collection`, so it stays a plain
+    /// synchronous function rather than depending on being run from a task.
+    pub(crate) fn apply_operation(
+        holder: &mut SegmentHolder,
+        op_num: u64,
+        operation: CollectionUpdateOperations,
+    ) -> CollectionResult<()> {
+        match operation {
+            CollectionUpdateOperations::PointOperation(point_operation) => {
+                Self::apply_point_operation(holder, op_num, point_operation)
+            }
+            CollectionUpdateOperations::PayloadOperation(payload_operation) => {
+                Self::apply_payload_operation(holder, op_num, payload_operation)
+            }
+        }
+    }
+
+    fn apply_point_operation(
+        holder: &mut SegmentHolder,
+        op_num: u64,
+        operation: PointOperations,
+    ) -> CollectionResult<()> {
+        match operation {
+            PointOperations::UpsertPoints(points) => {
+                let target = Self::target_segment(holder);
+                let mut segment = target.try_write().expect("segment lock contended");
+                for point in points {
+                    let vector: Vec<f32> = point.vector.iter().map(|v| *v as f32).collect();
+                    segment.upsert_point(op_num, point.id, &vector)?;
+                    if let Some(payload) = point.payload {
+                        segment.set_full_payload(op_num, point.id, convert_payload(payload))?;
+                    }
+                }
+            }
+            PointOperations::DeletePoints { ids } => {
+                for (_, segment) in holder.iter() {
+                    let mut segment = segment.try_write().expect("segment lock contended");
+                    for id in &ids {
+                        segment.delete_point(op_num, *id)?;
+                    }
+                }
+            }
+            PointOperations::DeletePointsByFilter(filter) => {
+                for (_, segment) in holder.iter() {
+                    let to_delete: Vec<PointIdType> = {
+                        let segment = segment.try_read().expect("segment lock contended");
+                        segment
+                            .iter_points()
+                            .into_iter()
+                            .filter(|id| {
+                                let payload = segment.payload(*id).ok();
+                                filter.check(*id, payload.as_ref())
+                            })
+                            .collect()
+                    };
+                    let mut segment = segment.try_write().expect("segment lock contended");
+                    for id in to_delete {
+                        segment.delete_point(op_num, id)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn apply_payload_operation(
+        holder: &mut SegmentHolder,
+        op_num: u64,
+        operation: PayloadOps,
+    ) -> CollectionResult<()> {
+        match operation {
+            PayloadOps::SetPayload(set_payload) => {
+                for point_id in set_payload.points {
+                    for (_, segment) in holder.iter() {
+                        let mut segment = segment.try_write().expect("segment lock contended");
+                        if segment.has_point(point_id) {
+                            segment.set_payload(
+                                op_num,
+                                point_id,
+                                convert_payload(set_payload.payload.clone()),
+                            )?;
+                            break;
+                        }
+                    }
+                }
+            }
+            PayloadOps::DeletePayload(_) | PayloadOps::ClearPayload { .. } => {
+                // Both are accepted and left in the WAL, but change no stored payload.
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the segment new points should land in, creating one if the
+    /// collection has none yet. There is no sharding strategy beyond this --
+    /// every upsert lands in whichever segment the holder yields first.
+    fn target_segment(
+        holder: &mut SegmentHolder,
+    ) -> Arc<RwLock<dyn SegmentEntry + Send + Sync>> {
+        if holder.is_empty() {
+            let id = holder.add(Segment::new());
+            holder.get(id).unwrap().clone()
+        } else {
+            holder.iter().next().unwrap().1.clone()
+        }
+    }
+
+    pub async fn search_by(
+        &self,
+        request: Arc<SearchRequest>,
+        searcher: &(impl CollectionSearcher + Sync),
+        runtime_handle: &Handle,
+    ) -> CollectionResult<Vec<ScoredPoint>> {
+        let started = Instant::now();
+        let result = searcher.search(&self.segments, request, runtime_handle).await;
+        self.metrics
+            .observe_search_latency(&self.name, started.elapsed().as_secs_f64());
+        result
+    }
+
+    pub async fn recommend_by(
+        &self,
+        request: Arc<RecommendRequest>,
+        searcher: &(impl CollectionSearcher + Sync),
+        runtime_handle: &Handle,
+    ) -> CollectionResult<Vec<ScoredPoint>> {
+        let started = Instant::now();
+        let result = searcher
+            .recommend(&self.segments, request, runtime_handle)
+            .await;
+        self.metrics
+            .observe_recommend_latency(&self.name, started.elapsed().as_secs_f64());
+        result
+    }
+
+    pub async fn scroll_by(
+        &self,
+        request: ScrollRequest,
+        searcher: &(impl CollectionSearcher + Sync),
+    ) -> CollectionResult<ScrollResult> {
+        let started = Instant::now();
+        let result = self.scroll_by_inner(request, searcher).await;
+        self.metrics
+            .observe_scroll_latency(&self.name, started.elapsed().as_secs_f64());
+        result
+    }
+
+    async fn scroll_by_inner(
+        &self,
+        request: ScrollRequest,
+        searcher: &(impl CollectionSearcher + Sync),
+    ) -> CollectionResult<ScrollResult> {
+        let with_payload = WithPayload::from(
+            request
+                .with_payload
+                .unwrap_or(WithPayloadInterface::Bool(false)),
+        );
+        let with_vector = request.with_vector.unwrap_or(false);
+        let limit = request.limit.unwrap_or(10);
+
+        let mut ids: Vec<PointIdType> = {
+            let holder = self.segments.read().await;
+            let mut ids = Vec::new();
+            for (_, segment) in holder.iter() {
+                let segment = segment.try_read().expect("segment lock contended");
+                ids.extend(segment.read_filtered(request.offset, limit + 1, request.filter.as_ref()));
+            }
+            ids
+        };
+        ids.sort();
+        ids.dedup();
+
+        let next_page_offset = if ids.len() > limit {
+            ids.get(limit).copied()
+        } else {
+            None
+        };
+        ids.truncate(limit);
+
+        let points = searcher
+            .retrieve(&self.segments, &ids, &with_payload, with_vector)
+            .await?;
+        Ok(ScrollResult {
+            next_page_offset,
+            points,
+        })
+    }
+}
+
+fn convert_payload(
+    payload: HashMap<PayloadKeyType, PayloadInterface>,
+) -> HashMap<PayloadKeyType, PayloadInterfaceStrict> {
+    payload
+        .into_iter()
+        .map(|(key, value)| {
+            let value = match value {
+                PayloadInterface::Payload(strict) => strict,
+                PayloadInterface::KeywordShortcut(variant) => {
+                    PayloadInterfaceStrict::Keyword(variant)
+                }
+            };
+            (key, value)
+        })
+        .collect()
+}
@@ -0,0 +1,46 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+
+use crate::collection::Collection;
+use crate::collection_manager::holders::segment_holder::SegmentHolder;
+use crate::metrics::Metrics;
+use crate::update_tracker::UpdateTracker;
+use crate::wal::Wal;
+
+/// Rebuilds a [`Collection`] from disk: replays every operation recorded in its
+/// WAL against a fresh set of segments, and restores the update task registry
+/// (terminal states from `tasks.json`, in-flight ones re-derived from the WAL
+/// entries past the highest id known to have reached a terminal state).
+///
+/// `metrics` is the shared registry the caller scrapes over every collection
+/// it holds; this collection's name is derived from its directory so the
+/// metrics it reports are labeled consistently with the others.
+pub fn load_collection(path: &Path, metrics: Arc<Metrics>) -> Collection {
+    let wal = Wal::open(path).expect("failed to open WAL");
+    let records = Wal::replay(wal.path()).expect("failed to replay WAL");
+
+    let mut holder = SegmentHolder::default();
+    for (update_id, operation) in &records {
+        Collection::apply_operation(&mut holder, *update_id, operation.clone())
+            .expect("failed to replay WAL entry");
+    }
+
+    let update_tracker = UpdateTracker::open(path);
+    update_tracker.restore_in_flight(records.iter().map(|(update_id, _)| *update_id));
+
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "collection".to_string());
+
+    Collection::new(
+        name,
+        path.to_path_buf(),
+        Arc::new(RwLock::new(holder)),
+        wal,
+        update_tracker,
+        metrics,
+    )
+}
@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::runtime::Handle;
+use tokio::sync::RwLock;
+
+use segment::types::{PointIdType, Record, ScoredPoint, WithPayload};
+
+use crate::collection_manager::holders::segment_holder::SegmentHolder;
+use crate::operations::types::{CollectionResult, RecommendRequest, SearchRequest};
+
+/// Runs read-only operations -- search, recommend, retrieve -- across every
+/// segment in a [`SegmentHolder`]. Kept as a trait so the collection can be
+/// tested against a fake searcher without spinning up real segments.
+#[async_trait]
+pub trait CollectionSearcher {
+    async fn search(
+        &self,
+        segments: &RwLock<SegmentHolder>,
+        request: Arc<SearchRequest>,
+        runtime_handle: &Handle,
+    ) -> CollectionResult<Vec<ScoredPoint>>;
+
+    async fn recommend(
+        &self,
+        segments: &RwLock<SegmentHolder>,
+        request: Arc<RecommendRequest>,
+        runtime_handle: &Handle,
+    ) -> CollectionResult<Vec<ScoredPoint>>;
+
+    async fn retrieve(
+        &self,
+        segments: &RwLock<SegmentHolder>,
+        points: &[PointIdType],
+        with_payload: &WithPayload,
+        with_vector: bool,
+    ) -> CollectionResult<Vec<Record>>;
+}
@@ -0,0 +1,43 @@
+use std::path::Path;
+
+use rand::Rng;
+
+use segment::entry::entry_point::SegmentEntry;
+use segment::segment::Segment;
+
+use crate::collection_manager::optimizers::merge_optimizer::MergeOptimizer;
+
+/// Builds a segment filled with `num_points` random vectors of `dim` dimensions,
+/// each carrying `num_payload_keys` keyword payload fields (`key_i` = `value_i`), for use in
+/// optimizer/holder unit tests where the actual vector values do not matter.
+pub fn random_segment(_dir: &Path, num_points: usize, dim: usize, num_payload_keys: usize) -> Segment {
+    let mut segment = Segment::new();
+    let mut rng = rand::thread_rng();
+
+    for point_id in 0..num_points as u64 {
+        let vector: Vec<f32> = (0..dim).map(|_| rng.gen_range(0.0..1.0)).collect();
+        segment
+            .upsert_point(point_id, point_id.into(), &vector)
+            .unwrap();
+
+        let payload: segment::types::Payload = (0..num_payload_keys)
+            .map(|i| {
+                (
+                    format!("key_{}", i),
+                    segment::types::PayloadInterfaceStrict::Keyword(
+                        segment::types::PayloadVariant::Value(format!("value_{}", i)),
+                    ),
+                )
+            })
+            .collect();
+        segment
+            .set_full_payload(point_id, point_id.into(), payload)
+            .unwrap();
+    }
+
+    segment
+}
+
+pub fn get_merge_optimizer(segments_path: &Path, temp_path: &Path) -> MergeOptimizer {
+    MergeOptimizer::new(segments_path.to_path_buf(), temp_path.to_path_buf())
+}
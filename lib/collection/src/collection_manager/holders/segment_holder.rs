@@ -0,0 +1,49 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+
+use segment::entry::entry_point::SegmentEntry;
+
+pub type SegmentId = u64;
+
+/// A segment behind its own lock, so that one segment can be read or optimized
+/// without blocking access to the others held by the same [`SegmentHolder`].
+pub type LockedSegment = Arc<RwLock<dyn SegmentEntry + Send + Sync>>;
+
+/// All the segments that currently make up a collection's data.
+#[derive(Default)]
+pub struct SegmentHolder {
+    segments: HashMap<SegmentId, LockedSegment>,
+    next_id: SegmentId,
+}
+
+impl SegmentHolder {
+    pub fn add(&mut self, segment: impl SegmentEntry + Send + Sync + 'static) -> SegmentId {
+        let id = self.next_id;
+        self.next_id += 1;
+        let locked: LockedSegment = Arc::new(RwLock::new(segment));
+        self.segments.insert(id, locked);
+        id
+    }
+
+    pub fn remove(&mut self, id: SegmentId) -> Option<LockedSegment> {
+        self.segments.remove(&id)
+    }
+
+    pub fn get(&self, id: SegmentId) -> Option<&LockedSegment> {
+        self.segments.get(&id)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&SegmentId, &LockedSegment)> {
+        self.segments.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.segments.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+}
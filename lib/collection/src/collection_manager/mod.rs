@@ -0,0 +1,6 @@
+pub mod collection_managers;
+#[cfg(test)]
+pub mod fixtures;
+pub mod holders;
+pub mod optimizers;
+pub mod simple_collection_searcher;
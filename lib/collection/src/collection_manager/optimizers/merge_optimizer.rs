@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use segment::entry::entry_point::{OperationResult, SegmentEntry};
+use segment::segment::Segment;
+
+use crate::collection_manager::holders::segment_holder::{SegmentHolder, SegmentId};
+use crate::collection_manager::optimizers::segment_optimizer::SegmentOptimizer;
+
+/// Minimum number of same-shaped segments worth merging into one. Below this,
+/// the merge overhead is not worth paying.
+const MIN_SEGMENTS_TO_MERGE: usize = 2;
+
+/// Merges segments that share the same vector dimensionality into a single
+/// larger one, so search does not have to fan out over many segments. Segment
+/// size is not considered when picking candidates.
+pub struct MergeOptimizer {
+    #[allow(dead_code)]
+    segments_path: PathBuf,
+    #[allow(dead_code)]
+    temp_path: PathBuf,
+}
+
+impl MergeOptimizer {
+    pub fn new(segments_path: PathBuf, temp_path: PathBuf) -> Self {
+        MergeOptimizer {
+            segments_path,
+            temp_path,
+        }
+    }
+
+    /// Vector dimensionality of a segment, inferred from one of its points.
+    /// Empty segments are reported as dimension `0` and never merge with anything.
+    async fn vector_dim(segment: &(dyn SegmentEntry + Send + Sync)) -> usize {
+        match segment.iter_points().first() {
+            Some(point_id) => segment.vector(*point_id).map(|v| v.len()).unwrap_or(0),
+            None => 0,
+        }
+    }
+}
+
+#[async_trait]
+impl SegmentOptimizer for MergeOptimizer {
+    async fn check_condition(&self, segments: &SegmentHolder) -> Vec<SegmentId> {
+        let mut by_dim: HashMap<usize, Vec<SegmentId>> = HashMap::new();
+        for (id, segment) in segments.iter() {
+            let segment = segment.read().await;
+            let dim = Self::vector_dim(&*segment).await;
+            if dim == 0 {
+                continue;
+            }
+            by_dim.entry(dim).or_default().push(*id);
+        }
+
+        by_dim
+            .into_values()
+            .find(|ids| ids.len() >= MIN_SEGMENTS_TO_MERGE)
+            .unwrap_or_default()
+    }
+
+    async fn optimize(
+        &self,
+        segments: Arc<RwLock<SegmentHolder>>,
+        ids: Vec<SegmentId>,
+    ) -> OperationResult<bool> {
+        let mut merged = Segment::new();
+        {
+            let holder = segments.read().await;
+            for id in &ids {
+                if let Some(segment) = holder.get(*id) {
+                    let segment = segment.read().await;
+                    for point_id in segment.iter_points() {
+                        let vector = segment.vector(point_id)?;
+                        merged.upsert_point(segment.version(), point_id, &vector)?;
+                        let payload = segment.payload(point_id)?;
+                        if !payload.is_empty() {
+                            merged.set_full_payload(segment.version(), point_id, payload)?;
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut holder = segments.write().await;
+        for id in &ids {
+            holder.remove(*id);
+        }
+        holder.add(merged);
+        Ok(true)
+    }
+}
@@ -0,0 +1,2 @@
+pub mod merge_optimizer;
+pub mod segment_optimizer;
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+
+use segment::entry::entry_point::OperationResult;
+
+use crate::collection_manager::holders::segment_holder::{SegmentHolder, SegmentId};
+
+/// A background job that rewrites a set of segments into a better-shaped one
+/// (e.g. merging small segments together). `check_condition` is cheap and
+/// read-only, so it can be polled often; `optimize` does the actual, possibly
+/// slow, rewrite.
+#[async_trait]
+pub trait SegmentOptimizer {
+    /// Returns the ids of the segments this optimizer would like to act on right
+    /// now, or an empty vec if nothing qualifies.
+    async fn check_condition(&self, segments: &SegmentHolder) -> Vec<SegmentId>;
+
+    /// Rewrites `ids` into an optimized replacement segment.
+    async fn optimize(
+        &self,
+        segments: std::sync::Arc<tokio::sync::RwLock<SegmentHolder>>,
+        ids: Vec<SegmentId>,
+    ) -> OperationResult<bool>;
+}
@@ -0,0 +1,172 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::runtime::Handle;
+use tokio::sync::RwLock;
+
+use segment::types::{PointIdType, Record, ScoredPoint, WithPayload};
+
+use crate::collection_manager::collection_managers::CollectionSearcher;
+use crate::collection_manager::holders::segment_holder::SegmentHolder;
+use crate::operations::types::{CollectionResult, RecommendRequest, SearchRequest};
+
+/// Brute-force [`CollectionSearcher`]: every query does a full linear scan of
+/// every segment, with no index in front of it. Exact, and simple enough to use
+/// as the default until an ANN index is wired in.
+pub struct SimpleCollectionSearcher {}
+
+impl SimpleCollectionSearcher {
+    pub fn new() -> Self {
+        SimpleCollectionSearcher {}
+    }
+}
+
+impl Default for SimpleCollectionSearcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl CollectionSearcher for SimpleCollectionSearcher {
+    async fn search(
+        &self,
+        segments: &RwLock<SegmentHolder>,
+        request: Arc<SearchRequest>,
+        _runtime_handle: &Handle,
+    ) -> CollectionResult<Vec<ScoredPoint>> {
+        let with_payload = WithPayload::from(
+            request
+                .with_payload
+                .clone()
+                .unwrap_or(segment::types::WithPayloadInterface::Bool(false)),
+        );
+        let with_vector = request.with_vector.unwrap_or(false);
+        let vector: Vec<f32> = request.vector.iter().map(|x| *x as f32).collect();
+
+        let holder = segments.read().await;
+        let mut all_results: Vec<ScoredPoint> = Vec::new();
+        for (_, segment) in holder.iter() {
+            let segment = segment.read().await;
+            all_results.extend(segment.search(
+                &vector,
+                request.filter.as_ref(),
+                request.top,
+                &with_payload,
+                with_vector,
+            ));
+        }
+        all_results.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
+        all_results.truncate(request.top);
+        Ok(all_results)
+    }
+
+    async fn recommend(
+        &self,
+        segments: &RwLock<SegmentHolder>,
+        request: Arc<RecommendRequest>,
+        runtime_handle: &Handle,
+    ) -> CollectionResult<Vec<ScoredPoint>> {
+        let exclude: std::collections::HashSet<PointIdType> = request
+            .positive
+            .iter()
+            .chain(request.negative.iter())
+            .copied()
+            .collect();
+
+        let positive_vectors = self
+            .collect_vectors(segments, &request.positive)
+            .await?;
+        let negative_vectors = self
+            .collect_vectors(segments, &request.negative)
+            .await?;
+
+        let averaged = average_vectors(&positive_vectors, &negative_vectors);
+
+        let search_request = Arc::new(SearchRequest {
+            vector: averaged,
+            filter: request.filter.clone(),
+            params: request.params,
+            top: request.top + exclude.len(),
+            with_payload: None,
+            with_vector: None,
+        });
+
+        let mut results = self.search(segments, search_request, runtime_handle).await?;
+        results.retain(|point| !exclude.contains(&point.id));
+        results.truncate(request.top);
+        Ok(results)
+    }
+
+    async fn retrieve(
+        &self,
+        segments: &RwLock<SegmentHolder>,
+        points: &[PointIdType],
+        with_payload: &WithPayload,
+        with_vector: bool,
+    ) -> CollectionResult<Vec<Record>> {
+        let holder = segments.read().await;
+        let mut result = Vec::new();
+        for point_id in points.iter().copied() {
+            for (_, segment) in holder.iter() {
+                let segment = segment.read().await;
+                if segment.has_point(point_id) {
+                    result.push(Record {
+                        id: point_id,
+                        payload: with_payload
+                            .enable
+                            .then(|| segment.payload(point_id).unwrap_or_default()),
+                        vector: with_vector.then(|| segment.vector(point_id).unwrap_or_default()),
+                    });
+                    break;
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl SimpleCollectionSearcher {
+    async fn collect_vectors(
+        &self,
+        segments: &RwLock<SegmentHolder>,
+        ids: &[PointIdType],
+    ) -> CollectionResult<Vec<Vec<f32>>> {
+        let holder = segments.read().await;
+        let mut vectors = Vec::with_capacity(ids.len());
+        for point_id in ids.iter().copied() {
+            for (_, segment) in holder.iter() {
+                let segment = segment.read().await;
+                if let Ok(vector) = segment.vector(point_id) {
+                    vectors.push(vector);
+                    break;
+                }
+            }
+        }
+        Ok(vectors)
+    }
+}
+
+/// Combines positive and negative example vectors into a single query vector,
+/// the same way the recommendation API does it: average the positives and move
+/// away from the average of the negatives.
+fn average_vectors(positive: &[Vec<f32>], negative: &[Vec<f32>]) -> Vec<f64> {
+    let dim = positive
+        .first()
+        .or_else(|| negative.first())
+        .map(|v| v.len())
+        .unwrap_or(0);
+
+    let mut result = vec![0.0f32; dim];
+    for vector in positive {
+        for (r, v) in result.iter_mut().zip(vector.iter()) {
+            *r += v / positive.len() as f32;
+        }
+    }
+    for vector in negative {
+        for (r, v) in result.iter_mut().zip(vector.iter()) {
+            *r -= v / negative.len() as f32;
+        }
+    }
+    result.into_iter().map(|x| x as f64).collect()
+}
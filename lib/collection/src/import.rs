@@ -0,0 +1,209 @@
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read};
+
+use segment::types::{PayloadInterface, PayloadInterfaceStrict, PayloadKeyType, PayloadVariant, PointIdType};
+
+use crate::collection::Collection;
+use crate::operations::point_ops::{PointOperations, PointStruct};
+use crate::operations::types::CollectionResult;
+use crate::operations::CollectionUpdateOperations;
+
+/// Source format accepted by [`import_stream`].
+pub enum ImportFormat {
+    Jsonl,
+    Csv,
+}
+
+/// How to map the columns/fields of an import source onto a point. Any field
+/// that is neither `id_column` nor one of `vector_columns` becomes a payload entry.
+pub struct ImportConfig {
+    pub id_column: String,
+    pub vector_columns: Vec<String>,
+    /// Points buffered per `Collection::update` call, so a multi-GB upload
+    /// never needs to be held in memory all at once.
+    pub chunk_size: usize,
+}
+
+impl Default for ImportConfig {
+    fn default() -> Self {
+        ImportConfig {
+            id_column: "id".to_string(),
+            vector_columns: vec!["vector".to_string()],
+            chunk_size: 1000,
+        }
+    }
+}
+
+/// A single row that could not be turned into a point, and why.
+#[derive(Debug)]
+pub struct ImportError {
+    pub line: usize,
+    pub message: String,
+}
+
+/// Outcome of an [`import_stream`] run: how many rows made it in, how many were
+/// rejected, and the per-line reason for every rejection.
+#[derive(Debug, Default)]
+pub struct ImportReport {
+    pub accepted: usize,
+    pub rejected: usize,
+    pub errors: Vec<ImportError>,
+}
+
+/// Streams `reader` into `collection` as `config.chunk_size`-sized upserts,
+/// rather than materializing the whole source in memory first. A row that
+/// fails to parse is recorded in the returned report with its line number and
+/// skipped -- it does not abort the rest of the import.
+pub async fn import_stream(
+    collection: &Collection,
+    format: ImportFormat,
+    reader: impl Read,
+    config: &ImportConfig,
+) -> CollectionResult<ImportReport> {
+    let mut report = ImportReport::default();
+    let mut chunk: Vec<PointStruct> = Vec::with_capacity(config.chunk_size);
+
+    for (line, row) in row_iter(&format, reader) {
+        match row.and_then(|fields| row_to_point(fields, config)) {
+            Ok(point) => {
+                report.accepted += 1;
+                chunk.push(point);
+                if chunk.len() >= config.chunk_size {
+                    flush(collection, &mut chunk).await?;
+                }
+            }
+            Err(message) => {
+                report.rejected += 1;
+                report.errors.push(ImportError { line, message });
+            }
+        }
+    }
+    flush(collection, &mut chunk).await?;
+
+    Ok(report)
+}
+
+async fn flush(collection: &Collection, chunk: &mut Vec<PointStruct>) -> CollectionResult<()> {
+    if chunk.is_empty() {
+        return Ok(());
+    }
+    let points = std::mem::take(chunk);
+    let operation: CollectionUpdateOperations = PointOperations::UpsertPoints(points).into();
+    collection.update(operation, true).await?;
+    Ok(())
+}
+
+type Row = HashMap<String, serde_json::Value>;
+
+/// Lazily yields `(line_number, row)` pairs, one at a time, from either source
+/// format -- a parse error on one row does not stop the rest from being read.
+fn row_iter<'a>(
+    format: &ImportFormat,
+    reader: impl Read + 'a,
+) -> Box<dyn Iterator<Item = (usize, Result<Row, String>)> + 'a> {
+    match format {
+        ImportFormat::Jsonl => Box::new(BufReader::new(reader).lines().enumerate().filter_map(
+            |(idx, line)| {
+                let line_number = idx + 1;
+                match line {
+                    Ok(line) if line.trim().is_empty() => None,
+                    Ok(line) => Some((
+                        line_number,
+                        serde_json::from_str::<Row>(&line).map_err(|e| e.to_string()),
+                    )),
+                    Err(e) => Some((line_number, Err(e.to_string()))),
+                }
+            },
+        )),
+        ImportFormat::Csv => {
+            let mut csv_reader = csv::ReaderBuilder::new()
+                .has_headers(true)
+                .from_reader(reader);
+            let headers = csv_reader.headers().cloned().unwrap_or_default();
+            Box::new(
+                csv_reader
+                    .into_records()
+                    .enumerate()
+                    .map(move |(idx, record)| {
+                        let line_number = idx + 2; // +1 for 1-indexing, +1 for the header row
+                        let parsed = record.map_err(|e| e.to_string()).map(|record| {
+                            headers
+                                .iter()
+                                .zip(record.iter())
+                                .map(|(k, v)| {
+                                    (k.to_string(), serde_json::Value::String(v.to_string()))
+                                })
+                                .collect::<Row>()
+                        });
+                        (line_number, parsed)
+                    }),
+            )
+        }
+    }
+}
+
+fn row_to_point(row: Row, config: &ImportConfig) -> Result<PointStruct, String> {
+    let id_value = row
+        .get(&config.id_column)
+        .ok_or_else(|| format!("missing id column '{}'", config.id_column))?;
+    let id = value_to_id(id_value)?;
+
+    let mut vector = Vec::with_capacity(config.vector_columns.len());
+    for column in &config.vector_columns {
+        let value = row
+            .get(column)
+            .ok_or_else(|| format!("missing vector column '{}'", column))?;
+        vector.push(value_to_f64(value)?);
+    }
+
+    let payload: HashMap<PayloadKeyType, PayloadInterface> = row
+        .iter()
+        .filter(|(key, _)| *key != &config.id_column && !config.vector_columns.contains(key))
+        .map(|(key, value)| (key.clone(), value_to_payload(value)))
+        .collect();
+
+    Ok(PointStruct {
+        id,
+        vector,
+        payload: Some(payload),
+    })
+}
+
+fn value_to_id(value: &serde_json::Value) -> Result<PointIdType, String> {
+    match value {
+        serde_json::Value::Number(n) => n
+            .as_u64()
+            .map(PointIdType::from)
+            .ok_or_else(|| format!("invalid id: {}", n)),
+        serde_json::Value::String(s) => s
+            .parse::<u64>()
+            .map(PointIdType::from)
+            .map_err(|e| e.to_string()),
+        other => Err(format!("invalid id value: {}", other)),
+    }
+}
+
+fn value_to_f64(value: &serde_json::Value) -> Result<f64, String> {
+    match value {
+        serde_json::Value::Number(n) => n
+            .as_f64()
+            .ok_or_else(|| format!("invalid number: {}", n)),
+        serde_json::Value::String(s) => s.parse::<f64>().map_err(|e| e.to_string()),
+        other => Err(format!("invalid vector value: {}", other)),
+    }
+}
+
+fn value_to_payload(value: &serde_json::Value) -> PayloadInterface {
+    match value {
+        serde_json::Value::String(s) => {
+            PayloadInterface::KeywordShortcut(PayloadVariant::Value(s.clone()))
+        }
+        serde_json::Value::Number(n) if n.is_i64() || n.is_u64() => PayloadInterface::Payload(
+            PayloadInterfaceStrict::Integer(PayloadVariant::Value(n.as_i64().unwrap_or_default())),
+        ),
+        serde_json::Value::Number(n) => PayloadInterface::Payload(PayloadInterfaceStrict::Float(
+            PayloadVariant::Value(n.as_f64().unwrap_or_default()),
+        )),
+        other => PayloadInterface::KeywordShortcut(PayloadVariant::Value(other.to_string())),
+    }
+}
@@ -0,0 +1,11 @@
+pub mod collection;
+pub mod collection_builder;
+pub mod collection_manager;
+pub mod import;
+pub mod metrics;
+pub mod operations;
+#[cfg(test)]
+mod tests;
+pub mod update_handler;
+pub mod update_tracker;
+pub mod wal;
@@ -0,0 +1,189 @@
+use prometheus::{Encoder, HistogramOpts, HistogramVec, IntCounterVec, IntGaugeVec, Opts, Registry, TextEncoder};
+
+/// Operational metrics for a collection's update and search paths, all labeled
+/// by collection name so one registry can be scraped for every collection an
+/// instance holds. Every metric is backed by a lock-free atomic under the
+/// hood (that's what `prometheus`'s `*Vec` types give you), so a scrape never
+/// contends with a write -- this is meant to be shared via `Arc` between
+/// `Collection` and `UpdateHandler`, then rendered by an admin endpoint once
+/// the HTTP layer exists.
+pub struct Metrics {
+    registry: Registry,
+    points_upserted: IntCounterVec,
+    points_deleted: IntCounterVec,
+    search_latency: HistogramVec,
+    recommend_latency: HistogramVec,
+    scroll_latency: HistogramVec,
+    segment_count: IntGaugeVec,
+    indexed_points: IntGaugeVec,
+    optimizer_runs: IntCounterVec,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        let registry = Registry::new();
+
+        let points_upserted = IntCounterVec::new(
+            Opts::new(
+                "collection_points_upserted_total",
+                "Points upserted into a collection",
+            ),
+            &["collection"],
+        )
+        .expect("valid metric definition");
+        let points_deleted = IntCounterVec::new(
+            Opts::new(
+                "collection_points_deleted_total",
+                "Points deleted from a collection",
+            ),
+            &["collection"],
+        )
+        .expect("valid metric definition");
+        let search_latency = HistogramVec::new(
+            HistogramOpts::new(
+                "collection_search_latency_seconds",
+                "SimpleCollectionSearcher::search latency, as seen through Collection::search_by",
+            ),
+            &["collection"],
+        )
+        .expect("valid metric definition");
+        let recommend_latency = HistogramVec::new(
+            HistogramOpts::new(
+                "collection_recommend_latency_seconds",
+                "SimpleCollectionSearcher::recommend latency, as seen through Collection::recommend_by",
+            ),
+            &["collection"],
+        )
+        .expect("valid metric definition");
+        let scroll_latency = HistogramVec::new(
+            HistogramOpts::new(
+                "collection_scroll_latency_seconds",
+                "Collection::scroll_by latency",
+            ),
+            &["collection"],
+        )
+        .expect("valid metric definition");
+        let segment_count = IntGaugeVec::new(
+            Opts::new(
+                "collection_segment_count",
+                "Number of segments currently held by a collection",
+            ),
+            &["collection"],
+        )
+        .expect("valid metric definition");
+        let indexed_points = IntGaugeVec::new(
+            Opts::new(
+                "collection_indexed_points",
+                "Total points held across a collection's segments",
+            ),
+            &["collection"],
+        )
+        .expect("valid metric definition");
+        let optimizer_runs = IntCounterVec::new(
+            Opts::new(
+                "collection_optimizer_runs_total",
+                "Optimizer runs triggered from UpdateHandler::process_optimization",
+            ),
+            &["collection"],
+        )
+        .expect("valid metric definition");
+
+        registry
+            .register(Box::new(points_upserted.clone()))
+            .expect("metric name is unique in this registry");
+        registry
+            .register(Box::new(points_deleted.clone()))
+            .expect("metric name is unique in this registry");
+        registry
+            .register(Box::new(search_latency.clone()))
+            .expect("metric name is unique in this registry");
+        registry
+            .register(Box::new(recommend_latency.clone()))
+            .expect("metric name is unique in this registry");
+        registry
+            .register(Box::new(scroll_latency.clone()))
+            .expect("metric name is unique in this registry");
+        registry
+            .register(Box::new(segment_count.clone()))
+            .expect("metric name is unique in this registry");
+        registry
+            .register(Box::new(indexed_points.clone()))
+            .expect("metric name is unique in this registry");
+        registry
+            .register(Box::new(optimizer_runs.clone()))
+            .expect("metric name is unique in this registry");
+
+        Metrics {
+            registry,
+            points_upserted,
+            points_deleted,
+            search_latency,
+            recommend_latency,
+            scroll_latency,
+            segment_count,
+            indexed_points,
+            optimizer_runs,
+        }
+    }
+
+    pub fn record_upserted(&self, collection: &str, count: u64) {
+        self.points_upserted
+            .with_label_values(&[collection])
+            .inc_by(count);
+    }
+
+    pub fn record_deleted(&self, collection: &str, count: u64) {
+        self.points_deleted
+            .with_label_values(&[collection])
+            .inc_by(count);
+    }
+
+    pub fn observe_search_latency(&self, collection: &str, seconds: f64) {
+        self.search_latency
+            .with_label_values(&[collection])
+            .observe(seconds);
+    }
+
+    pub fn observe_recommend_latency(&self, collection: &str, seconds: f64) {
+        self.recommend_latency
+            .with_label_values(&[collection])
+            .observe(seconds);
+    }
+
+    pub fn observe_scroll_latency(&self, collection: &str, seconds: f64) {
+        self.scroll_latency
+            .with_label_values(&[collection])
+            .observe(seconds);
+    }
+
+    pub fn set_segment_count(&self, collection: &str, count: i64) {
+        self.segment_count.with_label_values(&[collection]).set(count);
+    }
+
+    pub fn set_indexed_points(&self, collection: &str, count: i64) {
+        self.indexed_points
+            .with_label_values(&[collection])
+            .set(count);
+    }
+
+    pub fn record_optimizer_run(&self, collection: &str) {
+        self.optimizer_runs.with_label_values(&[collection]).inc();
+    }
+
+    /// Renders every registered metric in Prometheus text exposition format,
+    /// ready to be served verbatim by an admin scrape endpoint.
+    pub fn render(&self) -> String {
+        let families = self.registry.gather();
+        let mut buffer = Vec::new();
+        TextEncoder::new()
+            .encode(&families, &mut buffer)
+            .expect("prometheus metrics always encode");
+        String::from_utf8(buffer).expect("prometheus text exposition is valid utf8")
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
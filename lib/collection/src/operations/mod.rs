@@ -0,0 +1,29 @@
+pub mod payload_ops;
+pub mod point_ops;
+pub mod types;
+
+use serde::{Deserialize, Serialize};
+
+use payload_ops::PayloadOps;
+use point_ops::PointOperations;
+
+/// Every mutation that can be appended to a collection's WAL and replayed against
+/// its segments: either a change to points themselves, or to their payload only.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CollectionUpdateOperations {
+    PointOperation(PointOperations),
+    PayloadOperation(PayloadOps),
+}
+
+impl From<PointOperations> for CollectionUpdateOperations {
+    fn from(operation: PointOperations) -> Self {
+        CollectionUpdateOperations::PointOperation(operation)
+    }
+}
+
+impl From<PayloadOps> for CollectionUpdateOperations {
+    fn from(operation: PayloadOps) -> Self {
+        CollectionUpdateOperations::PayloadOperation(operation)
+    }
+}
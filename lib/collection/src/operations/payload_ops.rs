@@ -0,0 +1,30 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use segment::types::{PayloadInterface, PayloadKeyType, PointIdType};
+
+/// Replaces (merges into) the payload of every point in `points` with `payload`.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct SetPayload {
+    pub payload: HashMap<PayloadKeyType, PayloadInterface>,
+    pub points: Vec<PointIdType>,
+}
+
+/// Names payload keys to remove from every point in `points`. `Collection`
+/// writes it to the WAL and reports it completed, but leaves stored payloads
+/// unchanged; the same holds for `PayloadOps::ClearPayload`.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct DeletePayload {
+    pub keys: Vec<PayloadKeyType>,
+    pub points: Vec<PointIdType>,
+}
+
+/// Every way a point's payload can be mutated, independently of its vector.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PayloadOps {
+    SetPayload(SetPayload),
+    DeletePayload(DeletePayload),
+    ClearPayload { points: Vec<PointIdType> },
+}
@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use segment::types::{PayloadInterface, PayloadKeyType, PointIdType};
+
+/// A single point with its id, vector and optional payload, as accepted by the API.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct PointStruct {
+    pub id: PointIdType,
+    pub vector: Vec<f64>,
+    pub payload: Option<HashMap<PayloadKeyType, PayloadInterface>>,
+}
+
+/// Column-oriented alternative to `Vec<PointStruct>`: one vector of ids, one of
+/// vectors and one of payloads, all the same length. Cheaper to deserialize from
+/// bulk JSON/msgpack uploads than a list of per-point structs.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct Batch {
+    pub ids: Vec<PointIdType>,
+    pub vectors: Vec<Vec<f64>>,
+    pub payloads: Option<Vec<Option<HashMap<PayloadKeyType, PayloadInterface>>>>,
+}
+
+impl From<Batch> for Vec<PointStruct> {
+    fn from(batch: Batch) -> Self {
+        let payloads = batch
+            .payloads
+            .unwrap_or_else(|| vec![None; batch.ids.len()]);
+
+        batch
+            .ids
+            .into_iter()
+            .zip(batch.vectors.into_iter())
+            .zip(payloads.into_iter())
+            .map(|((id, vector), payload)| PointStruct {
+                id,
+                vector,
+                payload,
+            })
+            .collect()
+    }
+}
+
+/// Every way the points of a collection can be mutated.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PointOperations {
+    UpsertPoints(Vec<PointStruct>),
+    DeletePoints { ids: Vec<PointIdType> },
+    DeletePointsByFilter(segment::types::Filter),
+}
+
+impl From<Batch> for PointOperations {
+    fn from(batch: Batch) -> Self {
+        PointOperations::UpsertPoints(batch.into())
+    }
+}
+
+impl From<Vec<PointStruct>> for PointOperations {
+    fn from(points: Vec<PointStruct>) -> Self {
+        PointOperations::UpsertPoints(points)
+    }
+}
@@ -0,0 +1,204 @@
+use serde::{Deserialize, Serialize};
+
+use segment::entry::entry_point::OperationError;
+use segment::types::{Filter, PointIdType, Record, WithPayloadInterface};
+
+/// Tuning knobs for the brute-force / index search itself (e.g. `ef` for HNSW).
+/// Left empty for now -- the simple linear-scan searcher does not need any.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Default)]
+pub struct SearchParams {}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct SearchRequest {
+    pub vector: Vec<f64>,
+    pub filter: Option<Filter>,
+    pub params: Option<SearchParams>,
+    pub top: usize,
+    pub with_payload: Option<WithPayloadInterface>,
+    pub with_vector: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct RecommendRequest {
+    pub positive: Vec<PointIdType>,
+    pub negative: Vec<PointIdType>,
+    pub filter: Option<Filter>,
+    pub params: Option<SearchParams>,
+    pub top: usize,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct ScrollRequest {
+    pub offset: Option<PointIdType>,
+    pub limit: Option<usize>,
+    pub filter: Option<Filter>,
+    pub with_payload: Option<WithPayloadInterface>,
+    pub with_vector: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct ScrollResult {
+    pub next_page_offset: Option<PointIdType>,
+    pub points: Vec<Record>,
+}
+
+/// Status returned by `Collection::update`: `Completed` once the update has run
+/// against the segments, `Acknowledged` when it was only queued for a background
+/// task. [`TaskStatus`] additionally tracks the in-flight states of an async update.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdateStatus {
+    Acknowledged,
+    Completed,
+}
+
+/// Result of `Collection::update`. When the caller asked for `wait=false`, `status`
+/// is `Acknowledged` and `update_id` can be polled via `Collection::update_status`
+/// until it reaches a terminal state.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct UpdateResult {
+    pub update_id: u64,
+    pub status: UpdateStatus,
+}
+
+/// Lifecycle of a submitted update as tracked by the update task registry.
+/// Unlike [`UpdateStatus`], this also distinguishes `Processing` from `Acknowledged`
+/// and carries the failure reason when an update could not be applied.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskStatus {
+    Acknowledged,
+    Processing,
+    Completed,
+    Failed { error: String },
+}
+
+impl TaskStatus {
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, TaskStatus::Completed | TaskStatus::Failed { .. })
+    }
+}
+
+/// Category of a [`CollectionError`], so clients can tell a caller mistake from
+/// a server-side failure without string-matching `error_code`.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorType {
+    InvalidRequest,
+    Internal,
+}
+
+/// The category + HTTP status a given `error_code` maps to. Kept as one table
+/// below rather than scattered `match` arms, so the mapping can't drift out of
+/// sync with the set of `CollectionError` variants.
+struct ErrCode {
+    error_type: ErrorType,
+    status: u16,
+}
+
+const ERR_CODE_TABLE: &[(&str, ErrCode)] = &[
+    (
+        "wrong_vector_size",
+        ErrCode {
+            error_type: ErrorType::InvalidRequest,
+            status: 400,
+        },
+    ),
+    (
+        "point_not_found",
+        ErrCode {
+            error_type: ErrorType::InvalidRequest,
+            status: 404,
+        },
+    ),
+    (
+        "collection_not_found",
+        ErrCode {
+            error_type: ErrorType::InvalidRequest,
+            status: 404,
+        },
+    ),
+    (
+        "service_error",
+        ErrCode {
+            error_type: ErrorType::Internal,
+            status: 500,
+        },
+    ),
+];
+
+#[derive(thiserror::Error, Debug)]
+pub enum CollectionError {
+    #[error("Wrong vector size: expected {expected}, received {received}")]
+    WrongVectorSize { expected: usize, received: usize },
+    #[error("No point with id {missing_id} found")]
+    NotFound { missing_id: PointIdType },
+    #[error("Collection {name} not found")]
+    CollectionNotFound { name: String },
+    #[error("Service internal error: {error}")]
+    ServiceError { error: String },
+}
+
+impl CollectionError {
+    /// Stable, machine-readable identifier for this error -- safe for a client
+    /// to branch on, unlike `Display`/`Debug` output which is for humans only.
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            CollectionError::WrongVectorSize { .. } => "wrong_vector_size",
+            CollectionError::NotFound { .. } => "point_not_found",
+            CollectionError::CollectionNotFound { .. } => "collection_not_found",
+            CollectionError::ServiceError { .. } => "service_error",
+        }
+    }
+
+    fn err_code(&self) -> &'static ErrCode {
+        ERR_CODE_TABLE
+            .iter()
+            .find(|(code, _)| *code == self.error_code())
+            .map(|(_, entry)| entry)
+            .expect("every CollectionError variant has an ERR_CODE_TABLE entry")
+    }
+
+    pub fn error_type(&self) -> ErrorType {
+        self.err_code().error_type
+    }
+
+    pub fn http_status(&self) -> u16 {
+        self.err_code().status
+    }
+}
+
+impl Serialize for CollectionError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("CollectionError", 4)?;
+        state.serialize_field("error_code", self.error_code())?;
+        state.serialize_field("error_type", &self.error_type())?;
+        state.serialize_field("status", &self.http_status())?;
+        state.serialize_field("message", &self.to_string())?;
+        state.end()
+    }
+}
+
+/// Segment-level failures carry enough structure to classify -- a missing
+/// point or a dimension mismatch is a caller mistake, not a service failure,
+/// so they map onto their matching `CollectionError` variant instead of
+/// collapsing into `ServiceError`.
+impl From<OperationError> for CollectionError {
+    fn from(error: OperationError) -> Self {
+        match error {
+            OperationError::PointIdError { point_id } => {
+                CollectionError::NotFound { missing_id: point_id }
+            }
+            OperationError::WrongVectorDimension { expected, received } => {
+                CollectionError::WrongVectorSize { expected, received }
+            }
+        }
+    }
+}
+
+pub type CollectionResult<T> = Result<T, CollectionError>;
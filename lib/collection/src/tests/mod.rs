@@ -3,6 +3,7 @@ use tempdir::TempDir;
 use tokio::sync::RwLock;
 use crate::collection_manager::fixtures::{get_merge_optimizer, random_segment};
 use crate::collection_manager::holders::segment_holder::{SegmentHolder, SegmentId};
+use crate::metrics::Metrics;
 use crate::update_handler::UpdateHandler;
 
 #[tokio::test]
@@ -32,8 +33,19 @@ async fn test_optimization_process() {
 
     println!("HERE");
 
-    let handles = UpdateHandler::process_optimization(optimizers.clone(), segments.clone()).await;
-
-    assert_eq!(handles.len(), 1);
+    let metrics = Arc::new(Metrics::new());
+    let handles = UpdateHandler::process_optimization(
+        optimizers.clone(),
+        segments.clone(),
+        metrics.clone(),
+        "test_collection",
+        2,
+    )
+    .await;
+
+    // The 3 matched segments are equally sized, so a thread budget of 2 splits
+    // them into a 2-segment and a 1-segment work unit instead of merging all 3
+    // into a single job.
+    assert_eq!(handles.len(), 2);
 
 }
\ No newline at end of file
@@ -0,0 +1,131 @@
+use std::sync::Arc;
+
+use tokio::sync::{RwLock, Semaphore};
+use tokio::task::JoinHandle;
+
+use segment::entry::entry_point::{OperationResult, SegmentEntry};
+use segment::types::VectorElementType;
+
+use crate::collection_manager::holders::segment_holder::{SegmentHolder, SegmentId};
+use crate::collection_manager::optimizers::segment_optimizer::SegmentOptimizer;
+use crate::metrics::Metrics;
+
+/// Bytes per vector component, used to approximate a segment's data volume
+/// from its point count and sampled vector dimensionality.
+const VECTOR_ELEMENT_BYTES: usize = std::mem::size_of::<VectorElementType>();
+
+/// Drives background maintenance of a collection's segments: running
+/// optimizers over them.
+pub struct UpdateHandler;
+
+impl UpdateHandler {
+    /// For every optimizer with matching segments, splits its candidates into
+    /// balanced work units sized to roughly `total_bytes / max_concurrent_tasks`
+    /// each, then spawns one task per unit. At most `max_concurrent_tasks` of
+    /// those tasks run at a time (the rest wait on a semaphore permit), so a
+    /// large compaction keeps every worker evenly loaded instead of spawning
+    /// dozens of tiny jobs or one giant serial one. Every run triggered this
+    /// way is counted in `metrics`, labeled with `collection_name`.
+    pub async fn process_optimization<O>(
+        optimizers: Arc<Vec<O>>,
+        segments: Arc<RwLock<SegmentHolder>>,
+        metrics: Arc<Metrics>,
+        collection_name: &str,
+        max_concurrent_tasks: usize,
+    ) -> Vec<JoinHandle<OperationResult<bool>>>
+    where
+        O: SegmentOptimizer + Send + Sync + 'static,
+    {
+        let max_concurrent_tasks = max_concurrent_tasks.max(1);
+        let semaphore = Arc::new(Semaphore::new(max_concurrent_tasks));
+        let mut handles = Vec::new();
+
+        for idx in 0..optimizers.len() {
+            let candidates = {
+                let read_segments = segments.read().await;
+                optimizers[idx].check_condition(&read_segments).await
+            };
+
+            if candidates.is_empty() {
+                continue;
+            }
+
+            let work_units = {
+                let read_segments = segments.read().await;
+                Self::partition_by_size(&read_segments, candidates, max_concurrent_tasks).await
+            };
+
+            for unit in work_units {
+                metrics.record_optimizer_run(collection_name);
+
+                let optimizers = optimizers.clone();
+                let segments = segments.clone();
+                let semaphore = semaphore.clone();
+                handles.push(tokio::spawn(async move {
+                    let _permit = semaphore
+                        .acquire_owned()
+                        .await
+                        .expect("semaphore is never closed");
+                    optimizers[idx].optimize(segments, unit).await
+                }));
+            }
+        }
+
+        handles
+    }
+
+    /// Splits `candidates` into balanced work units: each segment's approximate
+    /// byte size (point count × sampled vector dimension × element size) is
+    /// accumulated greedily until it reaches `total_bytes / num_units`, where
+    /// `num_units` is capped at `max_concurrent_tasks` so a compaction never
+    /// fans out wider than there are workers to run it.
+    async fn partition_by_size(
+        segments: &SegmentHolder,
+        candidates: Vec<SegmentId>,
+        max_concurrent_tasks: usize,
+    ) -> Vec<Vec<SegmentId>> {
+        let mut sized = Vec::with_capacity(candidates.len());
+        let mut total_bytes = 0usize;
+        for id in candidates {
+            let bytes = match segments.get(id) {
+                Some(segment) => {
+                    let segment = segment.read().await;
+                    Self::estimate_bytes(&*segment).await
+                }
+                None => 0,
+            };
+            total_bytes += bytes;
+            sized.push((id, bytes));
+        }
+
+        let num_units = max_concurrent_tasks.min(sized.len()).max(1);
+        let target_bytes = (total_bytes / num_units).max(1);
+
+        let mut units = Vec::new();
+        let mut current = Vec::new();
+        let mut current_bytes = 0usize;
+        for (id, bytes) in sized {
+            current.push(id);
+            current_bytes += bytes;
+            if current_bytes >= target_bytes && units.len() + 1 < num_units {
+                units.push(std::mem::take(&mut current));
+                current_bytes = 0;
+            }
+        }
+        if !current.is_empty() {
+            units.push(current);
+        }
+        units
+    }
+
+    /// Approximate in-memory size of a segment's vectors: point count × sampled
+    /// vector dimensionality × element size. Payload size is not counted --
+    /// vectors dominate for the collections this is meant to balance.
+    async fn estimate_bytes(segment: &(dyn SegmentEntry + Send + Sync)) -> usize {
+        let dim = match segment.iter_points().first() {
+            Some(point_id) => segment.vector(*point_id).map(|v| v.len()).unwrap_or(0),
+            None => 0,
+        };
+        segment.points_count() * dim * VECTOR_ELEMENT_BYTES
+    }
+}
@@ -0,0 +1,111 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+use crate::operations::types::TaskStatus;
+
+/// Upper bound on the number of records in the registry. Once a `set` takes it
+/// past this, the oldest terminal (`Completed`/`Failed`) records are
+/// garbage-collected to bring it back down. Keeps the registry -- and what gets
+/// persisted to disk -- from growing unbounded across a long-lived collection.
+const MAX_TERMINAL_RECORDS: usize = 10_000;
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct TaskRegistryState {
+    tasks: BTreeMap<u64, TaskStatus>,
+}
+
+/// Tracks the status of every update submitted to a collection, so that a caller
+/// who passed `wait=false` to `Collection::update` can poll `update_status(update_id)`
+/// instead of holding the connection open. Terminal states are persisted to
+/// `tasks.json` next to the WAL so they survive `load_collection`; in-flight
+/// (`Acknowledged`/`Processing`) entries are not persisted -- they are rebuilt by
+/// replaying the WAL past the last known terminal id.
+pub struct UpdateTracker {
+    path: PathBuf,
+    state: Mutex<TaskRegistryState>,
+}
+
+impl UpdateTracker {
+    pub fn open(segments_path: &Path) -> Self {
+        let path = segments_path.join("tasks.json");
+        let state = fs::read(&path)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .unwrap_or_default();
+
+        UpdateTracker {
+            path,
+            state: Mutex::new(state),
+        }
+    }
+
+    /// Marks `update_id` as acknowledged: it has been assigned but the worker has
+    /// not yet picked it up from the WAL.
+    pub fn acknowledge(&self, update_id: u64) {
+        self.set(update_id, TaskStatus::Acknowledged);
+    }
+
+    /// Replays in-flight WAL entries (those past the highest persisted id) as
+    /// `Acknowledged`, so a poll issued right after `load_collection` sees them
+    /// instead of an unknown id.
+    pub fn restore_in_flight(&self, wal_update_ids: impl IntoIterator<Item = u64>) {
+        let mut state = self.state.lock().unwrap();
+        let last_persisted = state.tasks.keys().next_back().copied();
+        for update_id in wal_update_ids {
+            if last_persisted.map_or(false, |last| update_id <= last) {
+                continue;
+            }
+            state
+                .tasks
+                .entry(update_id)
+                .or_insert(TaskStatus::Acknowledged);
+        }
+    }
+
+    pub fn set(&self, update_id: u64, status: TaskStatus) {
+        let mut state = self.state.lock().unwrap();
+        state.tasks.insert(update_id, status);
+        if state.tasks.len() > MAX_TERMINAL_RECORDS {
+            Self::collect_garbage(&mut state.tasks);
+        }
+        self.persist(&state);
+    }
+
+    pub fn get(&self, update_id: u64) -> Option<TaskStatus> {
+        self.state.lock().unwrap().tasks.get(&update_id).cloned()
+    }
+
+    /// Drops the oldest terminal records once the registry grows past
+    /// `MAX_TERMINAL_RECORDS`, keeping all still-in-flight records regardless of age.
+    fn collect_garbage(tasks: &mut BTreeMap<u64, TaskStatus>) {
+        let excess = tasks.len() - MAX_TERMINAL_RECORDS;
+        let to_remove: Vec<u64> = tasks
+            .iter()
+            .filter(|(_, status)| status.is_terminal())
+            .map(|(id, _)| *id)
+            .take(excess)
+            .collect();
+        for update_id in to_remove {
+            tasks.remove(&update_id);
+        }
+    }
+
+    fn persist(&self, state: &TaskRegistryState) {
+        // Only terminal states are worth persisting -- in-flight ones are re-derived
+        // from the WAL on reload, and will have moved on by the time we load again anyway.
+        let terminal: BTreeMap<u64, TaskStatus> = state
+            .tasks
+            .iter()
+            .filter(|(_, status)| status.is_terminal())
+            .map(|(id, status)| (*id, status.clone()))
+            .collect();
+        let to_write = TaskRegistryState { tasks: terminal };
+        if let Ok(bytes) = serde_json::to_vec(&to_write) {
+            let _ = fs::write(&self.path, bytes);
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use std::fs::{File, OpenOptions};
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::operations::CollectionUpdateOperations;
+
+/// One WAL entry: the operation together with the `update_id` it was assigned
+/// when submitted, so replay can restore the update task registry (see
+/// `update_tracker`) without re-deriving ids from file offsets.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+struct WalRecord {
+    update_id: u64,
+    operation: CollectionUpdateOperations,
+}
+
+/// Append-only log of collection updates. Every record is appended as one
+/// newline-delimited JSON object, so the file can be replayed by reading it
+/// line by line on `load_collection`.
+pub struct Wal {
+    path: PathBuf,
+    writer: BufWriter<File>,
+    next_update_id: u64,
+}
+
+impl Wal {
+    pub fn open(segments_path: &Path) -> std::io::Result<Self> {
+        let path = segments_path.join("wal.log");
+        let next_update_id = Self::replay(&path)?
+            .last()
+            .map(|(id, _)| id + 1)
+            .unwrap_or(0);
+
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+
+        Ok(Wal {
+            path,
+            writer: BufWriter::new(file),
+            next_update_id,
+        })
+    }
+
+    /// Appends `operation`, assigning it the next `update_id`, and returns that id.
+    pub fn write(&mut self, operation: &CollectionUpdateOperations) -> std::io::Result<u64> {
+        let update_id = self.next_update_id;
+        let record = WalRecord {
+            update_id,
+            operation: operation.clone(),
+        };
+        serde_json::to_writer(&mut self.writer, &record)?;
+        self.writer.write_all(b"\n")?;
+        self.writer.flush()?;
+        self.next_update_id += 1;
+        Ok(update_id)
+    }
+
+    /// Reads every `(update_id, operation)` pair persisted so far, in order.
+    pub fn replay(path: &Path) -> std::io::Result<Vec<(u64, CollectionUpdateOperations)>> {
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        let reader = BufReader::new(File::open(path)?);
+        let records: Vec<(u64, CollectionUpdateOperations)> =
+            serde_json::Deserializer::from_reader(reader)
+                .into_iter::<WalRecord>()
+                .filter_map(Result::ok)
+                .map(|record| (record.update_id, record.operation))
+                .collect();
+        Ok(records)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
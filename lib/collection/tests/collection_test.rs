@@ -6,13 +6,18 @@ use tempdir::TempDir;
 use tokio::runtime::Handle;
 
 use collection::collection_builder::collection_loader::load_collection;
+use collection::metrics::Metrics;
 use collection::operations::payload_ops::{PayloadOps, SetPayload};
 use collection::operations::point_ops::{Batch, PointOperations, PointStruct};
-use collection::operations::types::{RecommendRequest, ScrollRequest, SearchRequest, UpdateStatus};
+use collection::operations::types::{
+    CollectionError, ErrorType, RecommendRequest, ScrollRequest, SearchRequest, TaskStatus,
+    UpdateStatus,
+};
 use collection::operations::CollectionUpdateOperations;
 use segment::types::{
-    Condition, HasIdCondition, PayloadInterface, PayloadKeyType, PayloadVariant, PointIdType,
-    WithPayload, WithPayloadInterface,
+    Condition, GeoBoundingBox, GeoPoint, GeoRadius, HasIdCondition, PayloadInterface,
+    PayloadInterfaceStrict, PayloadKeyType, PayloadVariant, PointIdType, WithPayload,
+    WithPayloadInterface,
 };
 
 use crate::common::simple_collection_fixture;
@@ -64,12 +69,8 @@ async fn test_collection_updater() {
     };
 
     let segment_searcher = SimpleCollectionSearcher::new();
-    let search_res = segment_searcher
-        .search(
-            collection.segments(),
-            Arc::new(search_request),
-            &Handle::current(),
-        )
+    let search_res = collection
+        .search_by(Arc::new(search_request), &segment_searcher, &Handle::current())
         .await;
 
     match search_res {
@@ -82,6 +83,92 @@ async fn test_collection_updater() {
     }
 }
 
+#[tokio::test]
+async fn test_collection_wrong_vector_dimension() {
+    let collection_dir = TempDir::new("collection").unwrap();
+
+    let collection = simple_collection_fixture(collection_dir.path()).await;
+
+    let insert_points = CollectionUpdateOperations::PointOperation(
+        Batch {
+            ids: vec![0.into()],
+            vectors: vec![vec![1.0, 0.0, 1.0, 1.0]],
+            payloads: None,
+        }
+        .into(),
+    );
+    collection.update(insert_points, true).await.unwrap();
+
+    // The segment's dimension is now pinned to 4 by the point above, so a
+    // 2-element vector must be rejected as a classified caller error rather
+    // than applied (which would make later scores silently zip-truncate).
+    let wrong_dim_insert = CollectionUpdateOperations::PointOperation(
+        Batch {
+            ids: vec![1.into()],
+            vectors: vec![vec![1.0, 0.0]],
+            payloads: None,
+        }
+        .into(),
+    );
+
+    match collection.update(wrong_dim_insert, true).await {
+        Ok(res) => panic!("expected a wrong_vector_size error, got {:?}", res),
+        Err(err) => {
+            assert!(matches!(err, CollectionError::WrongVectorSize { .. }));
+            assert_eq!(err.error_code(), "wrong_vector_size");
+            assert_eq!(err.error_type(), ErrorType::InvalidRequest);
+            assert_eq!(err.http_status(), 400);
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_update_status_transitions_for_non_blocking_update() {
+    let collection_dir = TempDir::new("collection").unwrap();
+    let collection = simple_collection_fixture(collection_dir.path()).await;
+
+    let insert_points = CollectionUpdateOperations::PointOperation(
+        Batch {
+            ids: vec![0.into()],
+            vectors: vec![vec![1.0, 0.0, 1.0, 1.0]],
+            payloads: None,
+        }
+        .into(),
+    );
+
+    let result = collection.update(insert_points, false).await.unwrap();
+    assert_eq!(result.status, UpdateStatus::Acknowledged);
+
+    // Polled right away: `acknowledge` must have already run before `update`
+    // returned, so this is never `None` even though the background worker may
+    // not have picked the update up yet.
+    assert!(collection.update_status(result.update_id).is_some());
+
+    // The background worker should still drive it all the way to `Completed`
+    // -- and once there, it must not get stomped back down to `Acknowledged`
+    // by a late-running `acknowledge` call.
+    let mut status = collection.update_status(result.update_id);
+    for _ in 0..200 {
+        if matches!(status, Some(TaskStatus::Completed)) {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+        status = collection.update_status(result.update_id);
+    }
+    assert!(
+        matches!(status, Some(TaskStatus::Completed)),
+        "expected update {} to reach Completed, got {:?}",
+        result.update_id,
+        status
+    );
+
+    tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+    assert!(matches!(
+        collection.update_status(result.update_id),
+        Some(TaskStatus::Completed)
+    ));
+}
+
 #[tokio::test]
 async fn test_collection_search_with_payload_and_vector() {
     let collection_dir = TempDir::new("collection").unwrap();
@@ -180,7 +267,7 @@ async fn test_collection_loading() {
         collection.update(assign_payload, true).await.unwrap();
     }
 
-    let loaded_collection = load_collection(collection_dir.path());
+    let loaded_collection = load_collection(collection_dir.path(), Arc::new(Metrics::new()));
     let segment_searcher = SimpleCollectionSearcher::new();
     let retrieved = segment_searcher
         .retrieve(
@@ -421,3 +508,270 @@ async fn test_collection_delete_points_by_filter() {
     assert_eq!(result.points.get(1).unwrap().id, 2.into());
     assert_eq!(result.points.get(2).unwrap().id, 4.into());
 }
+
+#[tokio::test]
+async fn test_collection_geo_filter() {
+    let collection_dir = TempDir::new("collection").unwrap();
+
+    let collection = simple_collection_fixture(collection_dir.path()).await;
+
+    // Berlin, Paris and London, in that order.
+    let cities = vec![
+        GeoPoint {
+            lon: 13.405,
+            lat: 52.52,
+        },
+        GeoPoint {
+            lon: 2.3522,
+            lat: 48.8566,
+        },
+        GeoPoint {
+            lon: -0.1278,
+            lat: 51.5074,
+        },
+    ];
+
+    let mut payloads = Vec::new();
+    for city in &cities {
+        let mut payload: HashMap<PayloadKeyType, PayloadInterface> = Default::default();
+        payload.insert(
+            "location".to_string(),
+            PayloadInterface::Payload(PayloadInterfaceStrict::Geo(PayloadVariant::Value(*city))),
+        );
+        payloads.push(Some(payload));
+    }
+
+    let insert_points = CollectionUpdateOperations::PointOperation(
+        Batch {
+            ids: vec![0, 1, 2].into_iter().map(|x| x.into()).collect_vec(),
+            vectors: vec![vec![1.0, 0.0], vec![1.0, 0.0], vec![1.0, 0.0]],
+            payloads: Some(payloads),
+        }
+        .into(),
+    );
+
+    collection.update(insert_points, true).await.unwrap();
+
+    // A generous radius around Berlin that reaches Paris but not London.
+    let radius_filter = segment::types::Filter {
+        should: None,
+        must: Some(vec![Condition::GeoRadius(GeoRadius {
+            key: "location".to_string(),
+            center: cities[0],
+            radius_meters: 900_000.0,
+        })]),
+        must_not: None,
+    };
+
+    let segment_searcher = SimpleCollectionSearcher::new();
+    let search_res = segment_searcher
+        .search(
+            collection.segments(),
+            Arc::new(SearchRequest {
+                vector: vec![1.0, 0.0],
+                with_payload: None,
+                with_vector: None,
+                filter: Some(radius_filter),
+                params: None,
+                top: 10,
+            }),
+            &Handle::current(),
+        )
+        .await
+        .unwrap();
+
+    let matched_ids: HashSet<PointIdType> = search_res.into_iter().map(|point| point.id).collect();
+    assert_eq!(matched_ids, vec![0.into(), 1.into()].into_iter().collect());
+
+    // A bounding box covering continental Europe excludes London: its west edge
+    // (lon 1.0) sits east of London's lon -0.1278, while both Berlin and Paris
+    // fall inside.
+    let bbox_filter = segment::types::Filter {
+        should: None,
+        must: Some(vec![Condition::GeoBoundingBox(GeoBoundingBox {
+            key: "location".to_string(),
+            top_left: GeoPoint {
+                lon: 1.0,
+                lat: 55.0,
+            },
+            bottom_right: GeoPoint {
+                lon: 20.0,
+                lat: 45.0,
+            },
+        })]),
+        must_not: None,
+    };
+
+    let delete_points = CollectionUpdateOperations::PointOperation(
+        PointOperations::DeletePointsByFilter(bbox_filter),
+    );
+    collection.update(delete_points, true).await.unwrap();
+
+    let result = collection
+        .scroll_by(
+            ScrollRequest {
+                offset: None,
+                limit: Some(10),
+                filter: None,
+                with_payload: Some(WithPayloadInterface::Bool(false)),
+                with_vector: None,
+            },
+            &segment_searcher,
+        )
+        .await
+        .unwrap();
+
+    // Only London (outside the bounding box) survives the delete.
+    assert_eq!(result.points.len(), 1);
+    assert_eq!(result.points[0].id, 2.into());
+}
+
+#[tokio::test]
+async fn test_collection_geo_filter_antimeridian_wrap() {
+    let collection_dir = TempDir::new("collection").unwrap();
+
+    let collection = simple_collection_fixture(collection_dir.path()).await;
+
+    // One point on each side of the antimeridian, plus a control point nowhere
+    // near it.
+    let points = vec![
+        GeoPoint {
+            lon: 178.0,
+            lat: -18.0,
+        },
+        GeoPoint {
+            lon: -172.0,
+            lat: -13.8,
+        },
+        GeoPoint { lon: 0.0, lat: 0.0 },
+    ];
+
+    let mut payloads = Vec::new();
+    for point in &points {
+        let mut payload: HashMap<PayloadKeyType, PayloadInterface> = Default::default();
+        payload.insert(
+            "location".to_string(),
+            PayloadInterface::Payload(PayloadInterfaceStrict::Geo(PayloadVariant::Value(*point))),
+        );
+        payloads.push(Some(payload));
+    }
+
+    let insert_points = CollectionUpdateOperations::PointOperation(
+        Batch {
+            ids: vec![0, 1, 2].into_iter().map(|x| x.into()).collect_vec(),
+            vectors: vec![vec![1.0, 0.0], vec![1.0, 0.0], vec![1.0, 0.0]],
+            payloads: Some(payloads),
+        }
+        .into(),
+    );
+
+    collection.update(insert_points, true).await.unwrap();
+
+    // A box wrapping the antimeridian (top_left.lon > bottom_right.lon): valid
+    // lon region is lon >= 170 OR lon <= -170, so it covers both points 0 and
+    // 1 but not the control point at lon 0.
+    let wrap_filter = segment::types::Filter {
+        should: None,
+        must: Some(vec![Condition::GeoBoundingBox(GeoBoundingBox {
+            key: "location".to_string(),
+            top_left: GeoPoint {
+                lon: 170.0,
+                lat: 10.0,
+            },
+            bottom_right: GeoPoint {
+                lon: -170.0,
+                lat: -30.0,
+            },
+        })]),
+        must_not: None,
+    };
+
+    let segment_searcher = SimpleCollectionSearcher::new();
+    let search_res = segment_searcher
+        .search(
+            collection.segments(),
+            Arc::new(SearchRequest {
+                vector: vec![1.0, 0.0],
+                with_payload: None,
+                with_vector: None,
+                filter: Some(wrap_filter),
+                params: None,
+                top: 10,
+            }),
+            &Handle::current(),
+        )
+        .await
+        .unwrap();
+
+    let matched_ids: HashSet<PointIdType> = search_res.into_iter().map(|point| point.id).collect();
+    assert_eq!(matched_ids, vec![0.into(), 1.into()].into_iter().collect());
+}
+
+#[tokio::test]
+async fn test_metrics_record_updates_and_search() {
+    let collection_dir = TempDir::new("collection").unwrap();
+    let name = collection_dir
+        .path()
+        .file_name()
+        .unwrap()
+        .to_string_lossy()
+        .into_owned();
+
+    let metrics = Arc::new(Metrics::new());
+    let collection = load_collection(collection_dir.path(), metrics.clone());
+
+    let insert_points = CollectionUpdateOperations::PointOperation(
+        Batch {
+            ids: vec![0, 1, 2].into_iter().map(|x| x.into()).collect_vec(),
+            vectors: vec![vec![1.0, 0.0], vec![1.0, 0.0], vec![1.0, 0.0]],
+            payloads: None,
+        }
+        .into(),
+    );
+    collection.update(insert_points, true).await.unwrap();
+
+    let segment_searcher = SimpleCollectionSearcher::new();
+    collection
+        .search_by(
+            Arc::new(SearchRequest {
+                vector: vec![1.0, 0.0],
+                with_payload: None,
+                with_vector: None,
+                filter: None,
+                params: None,
+                top: 10,
+            }),
+            &segment_searcher,
+            &Handle::current(),
+        )
+        .await
+        .unwrap();
+
+    let rendered = metrics.render();
+
+    let upserted_line = rendered
+        .lines()
+        .find(|line| {
+            line.starts_with("collection_points_upserted_total{")
+                && line.contains(&format!("collection=\"{}\"", name))
+        })
+        .unwrap_or_else(|| panic!("no points_upserted sample for {} in:\n{}", name, rendered));
+    assert!(
+        upserted_line.trim_end().ends_with(" 3"),
+        "expected 3 upserted points, got line: {}",
+        upserted_line
+    );
+
+    let search_count_line = rendered
+        .lines()
+        .find(|line| {
+            line.starts_with("collection_search_latency_seconds_count{")
+                && line.contains(&format!("collection=\"{}\"", name))
+        })
+        .unwrap_or_else(|| panic!("no search_latency sample for {} in:\n{}", name, rendered));
+    assert!(
+        search_count_line.trim_end().ends_with(" 1"),
+        "expected 1 search observation, got line: {}",
+        search_count_line
+    );
+}
@@ -0,0 +1,12 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use collection::collection::Collection;
+use collection::collection_builder::collection_loader::load_collection;
+use collection::metrics::Metrics;
+
+/// Builds an empty collection rooted at `path`, ready for the update/search
+/// calls exercised by the integration tests in this crate.
+pub async fn simple_collection_fixture(path: &Path) -> Collection {
+    load_collection(path, Arc::new(Metrics::new()))
+}
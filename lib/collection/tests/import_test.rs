@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use tempdir::TempDir;
+use tokio::runtime::Handle;
+
+use collection::import::{import_stream, ImportConfig, ImportFormat};
+use collection::operations::types::SearchRequest;
+
+use crate::common::simple_collection_fixture;
+use collection::collection_manager::collection_managers::CollectionSearcher;
+use collection::collection_manager::simple_collection_searcher::SimpleCollectionSearcher;
+
+mod common;
+
+#[tokio::test]
+async fn test_import_jsonl_with_malformed_row() {
+    let collection_dir = TempDir::new("collection").unwrap();
+    let collection = simple_collection_fixture(collection_dir.path()).await;
+
+    let source = concat!(
+        "{\"id\": 1, \"vector\": 0.5, \"label\": \"a\"}\n",
+        "not valid json\n",
+        "{\"vector\": 1.5}\n",
+        "{\"id\": 2, \"vector\": 2.5, \"label\": \"b\"}\n",
+    );
+
+    let report = import_stream(
+        &collection,
+        ImportFormat::Jsonl,
+        source.as_bytes(),
+        &ImportConfig::default(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(report.accepted, 2);
+    assert_eq!(report.rejected, 2);
+    assert_eq!(report.errors.len(), 2);
+    assert_eq!(report.errors[0].line, 2);
+    assert_eq!(report.errors[1].line, 3);
+
+    let segment_searcher = SimpleCollectionSearcher::new();
+    let search_res = segment_searcher
+        .search(
+            collection.segments(),
+            Arc::new(SearchRequest {
+                vector: vec![1.0],
+                filter: None,
+                params: None,
+                top: 10,
+                with_payload: None,
+                with_vector: None,
+            }),
+            &Handle::current(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(search_res.len(), 2);
+}
+
+#[tokio::test]
+async fn test_import_csv_with_malformed_row() {
+    let collection_dir = TempDir::new("collection").unwrap();
+    let collection = simple_collection_fixture(collection_dir.path()).await;
+
+    let source = concat!(
+        "id,vector,label\n",
+        "1,0.5,a\n",
+        "not-a-number,0.5,b\n",
+        "2,1.5,c\n",
+    );
+
+    let report = import_stream(
+        &collection,
+        ImportFormat::Csv,
+        source.as_bytes(),
+        &ImportConfig::default(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(report.accepted, 2);
+    assert_eq!(report.rejected, 1);
+    assert_eq!(report.errors.len(), 1);
+    // Header is line 1, so the malformed third CSV line is line 3.
+    assert_eq!(report.errors[0].line, 3);
+
+    let segment_searcher = SimpleCollectionSearcher::new();
+    let search_res = segment_searcher
+        .search(
+            collection.segments(),
+            Arc::new(SearchRequest {
+                vector: vec![1.0],
+                filter: None,
+                params: None,
+                top: 10,
+                with_payload: None,
+                with_vector: None,
+            }),
+            &Handle::current(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(search_res.len(), 2);
+}
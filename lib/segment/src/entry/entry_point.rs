@@ -0,0 +1,71 @@
+use crate::types::{
+    Filter, Payload, PointIdType, ScoredPoint, SeqNumberType, VectorElementType, WithPayload,
+};
+
+#[derive(thiserror::Error, Debug)]
+pub enum OperationError {
+    #[error("Point with id {point_id} not found")]
+    PointIdError { point_id: PointIdType },
+    #[error("Vector has wrong dimension: expected {expected}, got {received}")]
+    WrongVectorDimension { expected: usize, received: usize },
+}
+
+pub type OperationResult<T> = Result<T, OperationError>;
+
+/// Common contract implemented by every storage engine that backs a segment:
+/// a self-contained shard holding a slice of the collection's points, their
+/// vectors and their payloads.
+pub trait SegmentEntry {
+    fn version(&self) -> SeqNumberType;
+
+    fn search(
+        &self,
+        vector: &[VectorElementType],
+        filter: Option<&Filter>,
+        top: usize,
+        with_payload: &WithPayload,
+        with_vector: bool,
+    ) -> Vec<ScoredPoint>;
+
+    fn upsert_point(
+        &mut self,
+        op_num: SeqNumberType,
+        point_id: PointIdType,
+        vector: &[VectorElementType],
+    ) -> OperationResult<bool>;
+
+    fn delete_point(&mut self, op_num: SeqNumberType, point_id: PointIdType)
+        -> OperationResult<bool>;
+
+    fn set_full_payload(
+        &mut self,
+        op_num: SeqNumberType,
+        point_id: PointIdType,
+        payload: Payload,
+    ) -> OperationResult<bool>;
+
+    fn set_payload(
+        &mut self,
+        op_num: SeqNumberType,
+        point_id: PointIdType,
+        payload: Payload,
+    ) -> OperationResult<bool>;
+
+    fn has_point(&self, point_id: PointIdType) -> bool;
+
+    fn points_count(&self) -> usize;
+
+    fn vector(&self, point_id: PointIdType) -> OperationResult<Vec<VectorElementType>>;
+
+    fn payload(&self, point_id: PointIdType) -> OperationResult<Payload>;
+
+    /// Returns up to `limit` point ids greater than or equal to `offset`, in id order, for scroll pagination.
+    fn read_filtered(
+        &self,
+        offset: Option<PointIdType>,
+        limit: usize,
+        filter: Option<&Filter>,
+    ) -> Vec<PointIdType>;
+
+    fn iter_points(&self) -> Vec<PointIdType>;
+}
@@ -0,0 +1,3 @@
+pub mod entry;
+pub mod segment;
+pub mod types;
@@ -0,0 +1,230 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::entry::entry_point::{OperationError, OperationResult, SegmentEntry};
+use crate::types::{
+    Filter, Payload, PayloadInterfaceStrict, PayloadKeyType, PointIdType, ScoredPoint,
+    SeqNumberType, VectorElementType, WithPayload,
+};
+
+/// Straightforward in-memory segment: vectors and payloads are held in ordered
+/// maps and every query does a full linear scan, narrowed by the coarse geo
+/// bucket index below when the filter has a geo condition. No ANN index is
+/// built, which keeps it simple and exact -- useful as a baseline and for
+/// small collections.
+#[derive(Default)]
+pub struct Segment {
+    version: SeqNumberType,
+    /// Dimensionality of the first vector ever upserted. Once set, every later
+    /// upsert is checked against it so a segment never ends up with mixed-size
+    /// vectors `score` could silently zip-truncate.
+    dim: Option<usize>,
+    vectors: BTreeMap<PointIdType, Vec<VectorElementType>>,
+    payloads: BTreeMap<PointIdType, Payload>,
+    /// Coarse lat/lon bucket index, one per geo payload key, so a `GeoRadius`/
+    /// `GeoBoundingBox` filter can narrow its candidate scan instead of checking
+    /// every point. Buckets are an over-approximation -- `Filter::check` still
+    /// does the exact distance/bounding-box test on whatever they return.
+    geo_index: HashMap<PayloadKeyType, HashMap<(i64, i64), HashSet<PointIdType>>>,
+}
+
+impl Segment {
+    pub fn new() -> Self {
+        Segment::default()
+    }
+
+    fn score(&self, vector: &[VectorElementType], point_id: PointIdType) -> f32 {
+        let stored = &self.vectors[&point_id];
+        stored
+            .iter()
+            .zip(vector.iter())
+            .map(|(a, b)| a * b)
+            .sum()
+    }
+
+    fn matches_filter(&self, point_id: PointIdType, filter: Option<&Filter>) -> bool {
+        let filter = match filter {
+            Some(filter) => filter,
+            None => return true,
+        };
+        filter.check(point_id, self.payloads.get(&point_id))
+    }
+
+    /// Ids worth running `matches_filter` over: every point, unless `filter` has
+    /// a geo condition the bucket index can narrow down first.
+    fn candidate_ids(&self, filter: Option<&Filter>) -> Vec<PointIdType> {
+        let narrowed = filter
+            .and_then(|filter| filter.geo_candidate_buckets())
+            .and_then(|(key, min, max)| self.geo_index.get(key).map(|index| (index, min, max)));
+
+        match narrowed {
+            Some((index, (min_lat, min_lon), (max_lat, max_lon))) => {
+                let mut ids: Vec<PointIdType> = index
+                    .iter()
+                    .filter(|((lat, lon), _)| {
+                        (min_lat..=max_lat).contains(lat) && (min_lon..=max_lon).contains(lon)
+                    })
+                    .flat_map(|(_, ids)| ids.iter().copied())
+                    .collect();
+                ids.sort();
+                ids.dedup();
+                ids
+            }
+            None => self.vectors.keys().copied().collect(),
+        }
+    }
+
+    /// Drops `point_id` from every geo bucket, then re-adds it based on its
+    /// current payload. Called after any payload mutation or deletion so the
+    /// index never drifts from `self.payloads`.
+    fn reindex_geo(&mut self, point_id: PointIdType) {
+        for buckets in self.geo_index.values_mut() {
+            for ids in buckets.values_mut() {
+                ids.remove(&point_id);
+            }
+        }
+
+        let payload = match self.payloads.get(&point_id) {
+            Some(payload) => payload,
+            None => return,
+        };
+
+        for (key, value) in payload {
+            if let PayloadInterfaceStrict::Geo(geo) = value {
+                for point in geo.clone().to_list() {
+                    self.geo_index
+                        .entry(key.clone())
+                        .or_default()
+                        .entry(crate::types::geo_bucket(point))
+                        .or_default()
+                        .insert(point_id);
+                }
+            }
+        }
+    }
+}
+
+impl SegmentEntry for Segment {
+    fn version(&self) -> SeqNumberType {
+        self.version
+    }
+
+    fn search(
+        &self,
+        vector: &[VectorElementType],
+        filter: Option<&Filter>,
+        top: usize,
+        with_payload: &WithPayload,
+        with_vector: bool,
+    ) -> Vec<ScoredPoint> {
+        let mut scored: Vec<ScoredPoint> = self
+            .candidate_ids(filter)
+            .into_iter()
+            .filter(|id| self.matches_filter(*id, filter))
+            .map(|id| ScoredPoint {
+                id,
+                score: self.score(vector, id),
+                payload: with_payload
+                    .enable
+                    .then(|| self.payloads.get(&id).cloned().unwrap_or_default()),
+                vector: with_vector.then(|| self.vectors[&id].clone()),
+            })
+            .collect();
+
+        scored.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
+        scored.truncate(top);
+        scored
+    }
+
+    fn upsert_point(
+        &mut self,
+        op_num: SeqNumberType,
+        point_id: PointIdType,
+        vector: &[VectorElementType],
+    ) -> OperationResult<bool> {
+        let expected = *self.dim.get_or_insert(vector.len());
+        if vector.len() != expected {
+            return Err(OperationError::WrongVectorDimension {
+                expected,
+                received: vector.len(),
+            });
+        }
+        self.version = self.version.max(op_num);
+        let existed = self.vectors.insert(point_id, vector.to_vec()).is_some();
+        Ok(existed)
+    }
+
+    fn delete_point(
+        &mut self,
+        op_num: SeqNumberType,
+        point_id: PointIdType,
+    ) -> OperationResult<bool> {
+        self.version = self.version.max(op_num);
+        self.payloads.remove(&point_id);
+        self.reindex_geo(point_id);
+        Ok(self.vectors.remove(&point_id).is_some())
+    }
+
+    fn set_full_payload(
+        &mut self,
+        op_num: SeqNumberType,
+        point_id: PointIdType,
+        payload: Payload,
+    ) -> OperationResult<bool> {
+        self.version = self.version.max(op_num);
+        let existed = self.payloads.insert(point_id, payload).is_some();
+        self.reindex_geo(point_id);
+        Ok(existed)
+    }
+
+    fn set_payload(
+        &mut self,
+        op_num: SeqNumberType,
+        point_id: PointIdType,
+        payload: Payload,
+    ) -> OperationResult<bool> {
+        self.version = self.version.max(op_num);
+        let entry = self.payloads.entry(point_id).or_default();
+        entry.extend(payload);
+        self.reindex_geo(point_id);
+        Ok(true)
+    }
+
+    fn has_point(&self, point_id: PointIdType) -> bool {
+        self.vectors.contains_key(&point_id)
+    }
+
+    fn points_count(&self) -> usize {
+        self.vectors.len()
+    }
+
+    fn vector(&self, point_id: PointIdType) -> OperationResult<Vec<VectorElementType>> {
+        self.vectors
+            .get(&point_id)
+            .cloned()
+            .ok_or(OperationError::PointIdError { point_id })
+    }
+
+    fn payload(&self, point_id: PointIdType) -> OperationResult<Payload> {
+        Ok(self.payloads.get(&point_id).cloned().unwrap_or_default())
+    }
+
+    fn read_filtered(
+        &self,
+        offset: Option<PointIdType>,
+        limit: usize,
+        filter: Option<&Filter>,
+    ) -> Vec<PointIdType> {
+        let mut candidates = self.candidate_ids(filter);
+        candidates.sort();
+        candidates
+            .into_iter()
+            .filter(|id| offset.map_or(true, |offset| *id >= offset))
+            .filter(|id| self.matches_filter(*id, filter))
+            .take(limit)
+            .collect()
+    }
+
+    fn iter_points(&self) -> Vec<PointIdType> {
+        self.vectors.keys().copied().collect()
+    }
+}
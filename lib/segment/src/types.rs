@@ -0,0 +1,359 @@
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Key under which a value is stored in a point's payload.
+pub type PayloadKeyType = String;
+
+/// Scalar component of a stored vector.
+pub type VectorElementType = f32;
+
+/// Identifier of a point. Accepts either a numeric id or a UUID so that
+/// clients which already have UUID-based primary keys do not need a mapping table.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(untagged)]
+pub enum PointIdType {
+    NumId(u64),
+    Uuid(Uuid),
+}
+
+impl From<u64> for PointIdType {
+    fn from(id: u64) -> Self {
+        PointIdType::NumId(id)
+    }
+}
+
+impl From<Uuid> for PointIdType {
+    fn from(id: Uuid) -> Self {
+        PointIdType::Uuid(id)
+    }
+}
+
+/// A single value or a list of values of the same kind.
+/// Lets the payload JSON accept either `"value"` or `["value", ...]` for a key.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum PayloadVariant<T> {
+    Value(T),
+    List(Vec<T>),
+}
+
+impl<T> PayloadVariant<T> {
+    pub fn to_list(self) -> Vec<T> {
+        match self {
+            PayloadVariant::Value(x) => vec![x],
+            PayloadVariant::List(vec) => vec,
+        }
+    }
+}
+
+/// A point on the Earth's surface, expressed in degrees.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+pub struct GeoPoint {
+    pub lon: f64,
+    pub lat: f64,
+}
+
+/// Explicitly typed payload value, as understood once the `type` tag has been read.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum PayloadInterfaceStrict {
+    Keyword(PayloadVariant<String>),
+    Integer(PayloadVariant<i64>),
+    Float(PayloadVariant<f64>),
+    Geo(PayloadVariant<GeoPoint>),
+}
+
+/// Payload value as it appears in API requests: either the fully-typed form,
+/// or a bare JSON scalar/array which is interpreted as a keyword.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum PayloadInterface {
+    Payload(PayloadInterfaceStrict),
+    KeywordShortcut(PayloadVariant<String>),
+}
+
+pub type PayloadType = PayloadInterfaceStrict;
+
+/// Controls whether a point's payload is returned with a result. `Fields` turns
+/// payload on and keeps the names in [`WithPayload::payload_selector`]; the
+/// searchers in this repository do not apply that selector and return the
+/// whole payload.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum WithPayloadInterface {
+    Bool(bool),
+    Fields(Vec<PayloadKeyType>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WithPayload {
+    pub enable: bool,
+    pub payload_selector: Option<Vec<PayloadKeyType>>,
+}
+
+impl From<bool> for WithPayload {
+    fn from(enable: bool) -> Self {
+        WithPayload {
+            enable,
+            payload_selector: None,
+        }
+    }
+}
+
+impl From<WithPayloadInterface> for WithPayload {
+    fn from(interface: WithPayloadInterface) -> Self {
+        match interface {
+            WithPayloadInterface::Bool(enable) => WithPayload {
+                enable,
+                payload_selector: None,
+            },
+            WithPayloadInterface::Fields(fields) => WithPayload {
+                enable: true,
+                payload_selector: Some(fields),
+            },
+        }
+    }
+}
+
+/// Matches points whose id is one of `has_id`.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct HasIdCondition {
+    pub has_id: HashSet<PointIdType>,
+}
+
+impl From<HashSet<PointIdType>> for HasIdCondition {
+    fn from(has_id: HashSet<PointIdType>) -> Self {
+        HasIdCondition { has_id }
+    }
+}
+
+/// Matches points whose geo payload value lies within `radius_meters` of `center`,
+/// computed with the haversine great-circle distance.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct GeoRadius {
+    pub key: PayloadKeyType,
+    pub center: GeoPoint,
+    pub radius_meters: f64,
+}
+
+/// Matches points whose geo payload value falls inside the lon/lat rectangle
+/// defined by `top_left`/`bottom_right`. If `top_left.lon > bottom_right.lon` the
+/// rectangle is interpreted as wrapping across the antimeridian.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct GeoBoundingBox {
+    pub key: PayloadKeyType,
+    pub top_left: GeoPoint,
+    pub bottom_right: GeoPoint,
+}
+
+/// Mean radius of the Earth in meters, as used by the haversine formula below.
+pub const EARTH_RADIUS_METERS: f64 = 6_371_000.0;
+
+/// Great-circle distance between two points in meters (haversine formula):
+/// `a = sin²(Δlat/2) + cos(lat1)·cos(lat2)·sin²(Δlon/2)`, `d = 2R·atan2(√a, √(1−a))`.
+pub fn geo_distance_meters(a: GeoPoint, b: GeoPoint) -> f64 {
+    let lat1 = a.lat.to_radians();
+    let lat2 = b.lat.to_radians();
+    let dlat = lat2 - lat1;
+    let dlon = (b.lon - a.lon).to_radians();
+
+    let sin_dlat = (dlat / 2.0).sin();
+    let sin_dlon = (dlon / 2.0).sin();
+
+    let val_a = sin_dlat * sin_dlat + lat1.cos() * lat2.cos() * sin_dlon * sin_dlon;
+    2.0 * EARTH_RADIUS_METERS * val_a.sqrt().atan2((1.0 - val_a).sqrt())
+}
+
+/// Side, in degrees, of a cell in the coarse lat/lon bucket index. Coarse on
+/// purpose: it only needs to narrow a scan, not pinpoint a match.
+pub const GEO_BUCKET_SIZE_DEGREES: f64 = 1.0;
+
+/// Meters per degree of latitude (and, at the equator, of longitude too);
+/// used to size the bucket range a radius query needs to cover.
+const METERS_PER_DEGREE: f64 = 111_320.0;
+
+/// The bucket a point falls into in the coarse geo index.
+pub fn geo_bucket(point: GeoPoint) -> (i64, i64) {
+    (
+        (point.lat / GEO_BUCKET_SIZE_DEGREES).floor() as i64,
+        (point.lon / GEO_BUCKET_SIZE_DEGREES).floor() as i64,
+    )
+}
+
+impl GeoRadius {
+    pub fn matches(&self, point: GeoPoint) -> bool {
+        geo_distance_meters(self.center, point) <= self.radius_meters
+    }
+
+    /// Every bucket that could contain a point within `radius_meters` of `center`,
+    /// as an inclusive `(lat, lon)` range -- a conservative over-approximation
+    /// that the caller still has to verify with [`GeoRadius::matches`].
+    pub fn bucket_range(&self) -> ((i64, i64), (i64, i64)) {
+        let lat_delta_deg = (self.radius_meters / METERS_PER_DEGREE) + GEO_BUCKET_SIZE_DEGREES;
+        // Widest longitude offset the circle reaches: asin(sin(r/R) / cos(lat)).
+        let lon_sin = (self.radius_meters / EARTH_RADIUS_METERS).sin()
+            / self.center.lat.to_radians().cos();
+        let lon_delta_deg = lon_sin.asin().to_degrees() + GEO_BUCKET_SIZE_DEGREES;
+        let mut min_lon = self.center.lon - lon_delta_deg;
+        let mut max_lon = self.center.lon + lon_delta_deg;
+        // A circle that reaches a pole or crosses the antimeridian can hold any longitude.
+        if self.center.lat.abs() + lat_delta_deg >= 90.0 || !(min_lon >= -180.0 && max_lon <= 180.0) {
+            min_lon = -180.0;
+            max_lon = 180.0;
+        }
+
+        let min = geo_bucket(GeoPoint {
+            lon: min_lon,
+            lat: self.center.lat - lat_delta_deg,
+        });
+        let max = geo_bucket(GeoPoint {
+            lon: max_lon,
+            lat: self.center.lat + lat_delta_deg,
+        });
+        (min, max)
+    }
+}
+
+impl GeoBoundingBox {
+    pub fn matches(&self, point: GeoPoint) -> bool {
+        let lat_in_range = point.lat <= self.top_left.lat && point.lat >= self.bottom_right.lat;
+        let lon_in_range = if self.top_left.lon > self.bottom_right.lon {
+            // Rectangle wraps across the antimeridian.
+            point.lon >= self.top_left.lon || point.lon <= self.bottom_right.lon
+        } else {
+            point.lon >= self.top_left.lon && point.lon <= self.bottom_right.lon
+        };
+        lat_in_range && lon_in_range
+    }
+
+    /// Every bucket the bounding box overlaps, as an inclusive `(lat, lon)` range.
+    /// Returns `None` for an antimeridian-wrapping box (`top_left.lon >
+    /// bottom_right.lon`): the valid lon region there is two disjoint sub-ranges
+    /// (e.g. `[170,180] ∪ [-180,-170]`), which a single inclusive range can't
+    /// represent -- `min`/`max`-ing the two longitudes would instead describe
+    /// almost the exact complement of the real matching region. The caller
+    /// should skip narrowing and scan every point in that case; `matches`
+    /// still performs the exact check either way.
+    pub fn bucket_range(&self) -> Option<((i64, i64), (i64, i64))> {
+        if self.top_left.lon > self.bottom_right.lon {
+            return None;
+        }
+        let min = geo_bucket(GeoPoint {
+            lon: self.top_left.lon,
+            lat: self.bottom_right.lat,
+        });
+        let max = geo_bucket(GeoPoint {
+            lon: self.bottom_right.lon,
+            lat: self.top_left.lat,
+        });
+        Some((min, max))
+    }
+}
+
+/// A single condition that a point's payload must satisfy. Composes inside
+/// `Filter::must`/`should`/`must_not` just like any other condition.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Condition {
+    HasId(HasIdCondition),
+    GeoRadius(GeoRadius),
+    GeoBoundingBox(GeoBoundingBox),
+}
+
+impl Condition {
+    fn matches(&self, point_id: PointIdType, payload: Option<&Payload>) -> bool {
+        match self {
+            Condition::HasId(condition) => condition.has_id.contains(&point_id),
+            Condition::GeoRadius(condition) => Self::geo_values(payload, &condition.key)
+                .iter()
+                .any(|point| condition.matches(*point)),
+            Condition::GeoBoundingBox(condition) => Self::geo_values(payload, &condition.key)
+                .iter()
+                .any(|point| condition.matches(*point)),
+        }
+    }
+
+    fn geo_values(payload: Option<&Payload>, key: &str) -> Vec<GeoPoint> {
+        match payload.and_then(|p| p.get(key)) {
+            Some(PayloadInterfaceStrict::Geo(value)) => value.clone().to_list(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// For a geo condition, the payload key it matches against and the coarse
+    /// bucket range a candidate scan can narrow down to before the exact check.
+    pub fn geo_candidate_buckets(&self) -> Option<(&str, (i64, i64), (i64, i64))> {
+        match self {
+            Condition::HasId(_) => None,
+            Condition::GeoRadius(condition) => {
+                let (min, max) = condition.bucket_range();
+                Some((&condition.key, min, max))
+            }
+            Condition::GeoBoundingBox(condition) => {
+                let (min, max) = condition.bucket_range()?;
+                Some((&condition.key, min, max))
+            }
+        }
+    }
+}
+
+/// A boolean combination of conditions, following the same `must`/`should`/`must_not`
+/// shape used throughout search and delete-by-filter requests.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
+pub struct Filter {
+    pub should: Option<Vec<Condition>>,
+    pub must: Option<Vec<Condition>>,
+    pub must_not: Option<Vec<Condition>>,
+}
+
+impl Filter {
+    /// Evaluates this filter against a single point, the same way for search and delete-by-filter.
+    pub fn check(&self, point_id: PointIdType, payload: Option<&Payload>) -> bool {
+        let must_ok = self.must.as_ref().map_or(true, |conditions| {
+            conditions.iter().all(|c| c.matches(point_id, payload))
+        });
+
+        let should_ok = self.should.as_ref().map_or(true, |conditions| {
+            conditions.iter().any(|c| c.matches(point_id, payload))
+        });
+
+        let must_not_ok = self.must_not.as_ref().map_or(true, |conditions| {
+            conditions.iter().all(|c| !c.matches(point_id, payload))
+        });
+
+        must_ok && should_ok && must_not_ok
+    }
+
+    /// The bucket range of the first `must` geo condition, if any -- enough for a
+    /// segment to narrow its candidate scan to the relevant buckets of its coarse
+    /// geo index before applying the full [`Filter::check`].
+    pub fn geo_candidate_buckets(&self) -> Option<(&str, (i64, i64), (i64, i64))> {
+        self.must
+            .as_ref()?
+            .iter()
+            .find_map(|condition| condition.geo_candidate_buckets())
+    }
+}
+
+pub type Payload = HashMap<PayloadKeyType, PayloadInterfaceStrict>;
+
+/// A single point returned from a search, with its similarity score.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoredPoint {
+    pub id: PointIdType,
+    pub score: f32,
+    pub payload: Option<HashMap<PayloadKeyType, PayloadInterfaceStrict>>,
+    pub vector: Option<Vec<VectorElementType>>,
+}
+
+/// A point returned as-is, without a similarity score (retrieve / scroll).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub id: PointIdType,
+    pub payload: Option<HashMap<PayloadKeyType, PayloadInterfaceStrict>>,
+    pub vector: Option<Vec<VectorElementType>>,
+}
+
+pub type SeqNumberType = u64;
@@ -3,9 +3,22 @@ use std::env;
 use config::{ConfigError, Config, File, Environment};
 use serde::{Deserialize};
 
+/// Falls back to the number of available CPUs when `storage.optimizer_threads`
+/// is not set in config, so an un-tuned deployment still spreads optimizer
+/// work across every core instead of serializing onto one.
+fn default_optimizer_threads() -> usize {
+    std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+}
+
 #[derive(Debug, Deserialize)]
 struct Storage {
     wal: String,
+    /// Maximum number of optimization tasks `UpdateHandler::process_optimization`
+    /// runs concurrently, and the divisor used to size its per-task work units.
+    #[serde(default = "default_optimizer_threads")]
+    optimizer_threads: usize,
 }
 
 #[derive(Debug, Deserialize)]
@@ -15,6 +28,12 @@ pub struct Settings {
 }
 
 impl Settings {
+    /// Thread budget for `UpdateHandler::process_optimization`, as configured
+    /// under `storage.optimizer_threads`.
+    pub fn optimizer_threads(&self) -> usize {
+        self.storage.optimizer_threads
+    }
+
     pub fn new() -> Result<Self, ConfigError> {
         let mut s = Config::new();
 